@@ -0,0 +1,48 @@
+use crate::Keyframes;
+use render::ray_tracing::Color;
+use transform::Transform;
+
+/// Keyframe tracks for the components of one entity, components without a track are left alone
+#[derive(Debug, Default, Clone)]
+pub struct AnimationClip {
+    pub transform: Option<Keyframes<Transform>>,
+    /// Played back into the `base_color` of the [`render::ray_tracing::Material`]
+    pub base_color: Option<Keyframes<Color>>,
+    /// Played back into the `radius` of the [`render::ray_tracing::HyperSphere`]
+    pub radius: Option<Keyframes<f32>>,
+}
+
+impl AnimationClip {
+    #[must_use]
+    pub fn with_transform(self, transform: Keyframes<Transform>) -> Self {
+        Self {
+            transform: Some(transform),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_base_color(self, base_color: Keyframes<Color>) -> Self {
+        Self {
+            base_color: Some(base_color),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_radius(self, radius: Keyframes<f32>) -> Self {
+        Self {
+            radius: Some(radius),
+            ..self
+        }
+    }
+
+    /// The time of the last keyframe of any track
+    #[must_use]
+    pub fn duration(&self) -> f32 {
+        let transform = self.transform.as_ref().map_or(0.0, Keyframes::duration);
+        let base_color = self.base_color.as_ref().map_or(0.0, Keyframes::duration);
+        let radius = self.radius.as_ref().map_or(0.0, Keyframes::duration);
+        transform.max(base_color).max(radius)
+    }
+}
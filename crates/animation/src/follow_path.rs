@@ -0,0 +1,195 @@
+use crate::{Path, RepeatMode};
+use bevy::{
+    ecs::{
+        component::Component,
+        system::{Query, Res},
+    },
+    time::Time,
+};
+use std::sync::Arc;
+use transform::{Rotor, Transform, Vec4};
+
+/// Moves an entity along a [`Path`] at a constant speed, the path is in the space of its parent
+#[derive(Component, Debug, Clone)]
+#[require(Transform)]
+pub struct FollowPath {
+    pub path: Arc<Path>,
+    /// Units per second, a negative speed moves back towards the start
+    pub speed: f32,
+    /// How far it has moved along the path, this keeps counting past the end when repeating
+    pub distance: f32,
+    pub repeat: RepeatMode,
+    /// Turns the forward (x) axis to point the way the entity is moving, by the smallest rotation from where it pointed before,
+    /// paths that have their own rotation like a [`crate::MotorSpline`] set it either way
+    pub align: bool,
+}
+
+impl FollowPath {
+    #[must_use]
+    pub fn new(path: Arc<Path>, speed: f32) -> Self {
+        Self {
+            path,
+            speed,
+            distance: 0.0,
+            repeat: RepeatMode::Once,
+            align: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_repeat(self, repeat: RepeatMode) -> Self {
+        Self { repeat, ..self }
+    }
+
+    #[must_use]
+    pub fn aligned(self) -> Self {
+        Self {
+            align: true,
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.repeat
+            .is_finished(self.distance, self.speed, self.path.length())
+    }
+
+    /// Whether it is heading back towards the start of the path
+    fn is_reversing(&self) -> bool {
+        let length = self.path.length();
+        let returning = self.repeat == RepeatMode::PingPong
+            && length > 0.0
+            && (self.distance / length).rem_euclid(2.0) >= 1.0;
+        (self.speed < 0.0) != returning
+    }
+}
+
+pub(crate) fn follow_paths(
+    time: Res<Time>,
+    mut followers: Query<(&mut FollowPath, &mut Transform)>,
+) {
+    followers
+        .par_iter_mut()
+        .for_each(|(mut follow_path, mut transform)| {
+            if follow_path.speed == 0.0 || follow_path.is_finished() {
+                return;
+            }
+            follow_path.distance += time.delta_secs() * follow_path.speed;
+            let length = follow_path.path.length();
+            let distance = follow_path.repeat.wrap(follow_path.distance, length);
+            if follow_path.is_finished() {
+                follow_path.distance = distance;
+            }
+
+            let path = &follow_path.path;
+            let rotor = transform.rotor_part();
+            let rotor = match path.rotor_at(distance) {
+                Some(rotor) => rotor,
+                None if follow_path.align => {
+                    let direction = path.direction_at(distance);
+                    let direction = if follow_path.is_reversing() {
+                        -direction
+                    } else {
+                        direction
+                    };
+                    if direction == Vec4::ZERO {
+                        rotor
+                    } else {
+                        Rotor::from_to(rotor.rotate(Vec4::X), direction).then(rotor)
+                    }
+                }
+                None => rotor,
+            };
+            *transform =
+                Transform::from_translation_rotor(path.position_at(distance).to_vector(), rotor);
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AnimationPlugin, CatmullRom};
+    use bevy::{
+        app::App,
+        time::{TimePlugin, TimeUpdateStrategy},
+    };
+    use std::time::Duration;
+    use transform::Point4;
+
+    const TOLERANCE: f32 = 1e-4;
+
+    /// Runs `updates` frames of a quarter of a second, the first of which has no time pass
+    fn follow(follow_path: FollowPath, updates: usize) -> Transform {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, AnimationPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                250,
+            )));
+        let entity = app.world_mut().spawn(follow_path).id();
+        for _ in 0..updates {
+            app.update();
+        }
+        *app.world().get::<Transform>(entity).unwrap()
+    }
+
+    #[test]
+    fn ping_pong_turns_around_at_both_ends() {
+        // 4 long, moving 1 along it each frame
+        let path = Arc::new(Path::new(CatmullRom::new([
+            Point4::ORIGIN,
+            Point4::new(4.0, 0.0, 0.0, 0.0),
+        ])));
+        let follow_path = FollowPath::new(path, 4.0)
+            .with_repeat(RepeatMode::PingPong)
+            .aligned();
+        // at either end it already faces the way it leaves
+        for (updates, x, forward) in [
+            (4, 3.0, Vec4::X),
+            (5, 4.0, -Vec4::X),
+            (6, 3.0, -Vec4::X),
+            (8, 1.0, -Vec4::X),
+            (9, 0.0, Vec4::X),
+            (10, 1.0, Vec4::X),
+            (13, 4.0, -Vec4::X),
+            (14, 3.0, -Vec4::X),
+        ] {
+            let transform = follow(follow_path.clone(), updates);
+            let translation = transform.translation_part();
+            assert!(
+                translation.abs_diff_eq(Vec4::new(x, 0.0, 0.0, 0.0), TOLERANCE),
+                "{updates} {translation:?}"
+            );
+            let heading = transform.rotor_part().rotate(Vec4::X);
+            assert!(
+                heading.abs_diff_eq(forward, TOLERANCE),
+                "{updates} {heading:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn aligned_followers_point_along_the_path() {
+        let path = Arc::new(Path::new(CatmullRom::new([
+            Point4::ORIGIN,
+            Point4::new(2.0, 2.0, 0.0, 1.0),
+            Point4::new(4.0, 0.0, -1.0, 3.0),
+            Point4::new(5.0, -2.0, 2.0, 3.0),
+        ])));
+        let follow_path = FollowPath::new(path.clone(), 1.0).aligned();
+        for updates in [2, 5, 9, 14] {
+            let distance = (updates - 1) as f32 * 0.25;
+            let transform = follow(follow_path.clone(), updates);
+            let translation = transform.translation_part();
+            assert!(
+                translation.abs_diff_eq(path.position_at(distance).to_vector(), TOLERANCE),
+                "{updates} {translation:?}"
+            );
+            let heading = transform.rotor_part().rotate(Vec4::X);
+            assert!(
+                heading.abs_diff_eq(path.direction_at(distance), TOLERANCE),
+                "{updates} {heading:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use render::ray_tracing::Color;
+use transform::Transform;
+
+/// How values are found between two keyframes
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Keeps the value of the earlier keyframe until the next one is reached
+    Step,
+    #[default]
+    Linear,
+    /// Moves along the shortest screw motion at a constant speed, the same as `Linear` for anything that isnt a [`Transform`]
+    Slerp,
+}
+
+/// Something keyframes can be made of
+pub trait Animatable: Copy {
+    #[must_use]
+    fn lerp(self, other: Self, t: f32) -> Self;
+
+    #[must_use]
+    fn slerp(self, other: Self, t: f32) -> Self {
+        self.lerp(other, t)
+    }
+}
+
+impl Animatable for f32 {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Animatable for Color {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            red: Animatable::lerp(self.red, other.red, t),
+            green: Animatable::lerp(self.green, other.green, t),
+            blue: Animatable::lerp(self.blue, other.blue, t),
+        }
+    }
+}
+
+/// [`Transform::lerp`] moves the translation in a straight line while the rotation turns,
+/// [`Transform::slerp`] screws the whole transform along one motion
+impl Animatable for Transform {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Transform::lerp(self, other, t)
+    }
+
+    fn slerp(self, other: Self, t: f32) -> Self {
+        Transform::slerp(self, other, t)
+    }
+}
+
+/// Values at points in time, in seconds from the start of the clip
+#[derive(Debug, Clone)]
+pub struct Keyframes<T> {
+    pub interpolation: Interpolation,
+    keyframes: Vec<(f32, T)>,
+}
+
+impl<T: Animatable> Keyframes<T> {
+    /// The keyframes dont have to be in order
+    #[must_use]
+    pub fn new(
+        interpolation: Interpolation,
+        keyframes: impl IntoIterator<Item = (f32, T)>,
+    ) -> Self {
+        let mut keyframes = keyframes.into_iter().collect::<Vec<_>>();
+        keyframes.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        Self {
+            interpolation,
+            keyframes,
+        }
+    }
+
+    #[must_use]
+    pub fn keyframes(&self) -> &[(f32, T)] {
+        &self.keyframes
+    }
+
+    /// The time of the last keyframe
+    #[must_use]
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |&(time, _)| time)
+    }
+
+    /// The value at `time`, before the first keyframe and after the last one the value is held,
+    /// `None` if there are no keyframes
+    #[must_use]
+    pub fn sample(&self, time: f32) -> Option<T> {
+        let next = self
+            .keyframes
+            .partition_point(|&(keyframe_time, _)| keyframe_time <= time);
+        let Some(previous) = next.checked_sub(1) else {
+            return self.keyframes.first().map(|&(_, value)| value);
+        };
+        let (start_time, start) = self.keyframes[previous];
+        let Some(&(end_time, end)) = self.keyframes.get(next) else {
+            return Some(start);
+        };
+
+        let t = (time - start_time) / (end_time - start_time);
+        Some(match self.interpolation {
+            Interpolation::Step => start,
+            Interpolation::Linear => start.lerp(end, t),
+            Interpolation::Slerp => start.slerp(end, t),
+        })
+    }
+}
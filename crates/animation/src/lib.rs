@@ -0,0 +1,23 @@
+use bevy::app::{App, Plugin, Update};
+
+mod clip;
+mod follow_path;
+mod keyframes;
+mod player;
+mod spline;
+
+pub use clip::AnimationClip;
+pub use follow_path::FollowPath;
+pub use keyframes::{Animatable, Interpolation, Keyframes};
+pub use player::{AnimationPlayer, RepeatMode};
+pub use spline::{CatmullRom, CubicBezier, Curve, MotorSpline, Path};
+
+pub struct AnimationPlugin;
+
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<AnimationPlayer>()
+            .register_type::<RepeatMode>()
+            .add_systems(Update, (player::play_animations, follow_path::follow_paths));
+    }
+}
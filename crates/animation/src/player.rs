@@ -0,0 +1,311 @@
+use crate::AnimationClip;
+use bevy::{
+    ecs::{
+        component::Component,
+        entity::Entity,
+        system::{Commands, Query, Res},
+    },
+    reflect::{Reflect, prelude::ReflectDefault},
+    time::Time,
+};
+use render::ray_tracing::{HyperSphere, Material};
+use std::sync::Arc;
+use transform::Transform;
+
+/// What happens once playback reaches either end of a clip or a path
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[reflect(Default, Clone)]
+pub enum RepeatMode {
+    /// Stops and holds the end it reached
+    #[default]
+    Once,
+    /// Jumps back to the start
+    Loop,
+    /// Plays backwards to the start and then forwards again
+    PingPong,
+}
+
+impl RepeatMode {
+    /// Where `elapsed` ends up within something `length` long
+    #[must_use]
+    pub fn wrap(self, elapsed: f32, length: f32) -> f32 {
+        if length <= 0.0 {
+            return 0.0;
+        }
+        match self {
+            Self::Once => elapsed.clamp(0.0, length),
+            Self::Loop => elapsed.rem_euclid(length),
+            Self::PingPong => {
+                let elapsed = elapsed.rem_euclid(length * 2.0);
+                if elapsed > length {
+                    length * 2.0 - elapsed
+                } else {
+                    elapsed
+                }
+            }
+        }
+    }
+
+    /// Whether the end `elapsed` is heading towards is the end of the last repetition
+    #[must_use]
+    pub fn is_finished(self, elapsed: f32, speed: f32, length: f32) -> bool {
+        self == Self::Once
+            && if speed < 0.0 {
+                elapsed <= 0.0
+            } else {
+                elapsed >= length
+            }
+    }
+}
+
+/// Plays an [`AnimationClip`] back into the components of its entity, the clip is shared
+/// so many entities can play it at once
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Clone)]
+pub struct AnimationPlayer {
+    /// Not reflected, an empty clip takes its place when the player is built from reflection
+    #[reflect(ignore)]
+    pub clip: Arc<AnimationClip>,
+    /// Seconds of playback since the start, this keeps counting past the end when repeating
+    pub elapsed: f32,
+    /// A negative speed plays the clip backwards
+    pub speed: f32,
+    pub repeat: RepeatMode,
+    pub paused: bool,
+}
+
+impl AnimationPlayer {
+    #[must_use]
+    pub fn new(clip: Arc<AnimationClip>) -> Self {
+        Self {
+            clip,
+            elapsed: 0.0,
+            speed: 1.0,
+            repeat: RepeatMode::Once,
+            paused: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_repeat(self, repeat: RepeatMode) -> Self {
+        Self { repeat, ..self }
+    }
+
+    #[must_use]
+    pub fn with_speed(self, speed: f32) -> Self {
+        Self { speed, ..self }
+    }
+
+    /// Whether a clip that doesnt repeat has reached the end it is playing towards
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.repeat
+            .is_finished(self.elapsed, self.speed, self.clip.duration())
+    }
+
+    /// The time within the clip the keyframes are sampled at
+    #[must_use]
+    pub fn clip_time(&self) -> f32 {
+        self.repeat.wrap(self.elapsed, self.clip.duration())
+    }
+}
+
+/// Runs in `Update`, so the transforms it writes are propagated in the same frame
+pub(crate) fn play_animations(
+    time: Res<Time>,
+    mut players: Query<(
+        Entity,
+        &mut AnimationPlayer,
+        Option<&mut Transform>,
+        Option<&mut HyperSphere>,
+        Option<&Material>,
+    )>,
+    mut commands: Commands,
+) {
+    for (entity, mut player, transform, hyper_sphere, material) in &mut players {
+        // leaving finished players alone keeps their components from being flagged as changed every frame
+        if player.paused || player.is_finished() {
+            continue;
+        }
+        player.elapsed += time.delta_secs() * player.speed;
+        if player.is_finished() {
+            // dont overshoot, so that reversing the speed plays back from the end straight away
+            player.elapsed = player.clip_time();
+        }
+
+        let clip_time = player.clip_time();
+        let clip = &player.clip;
+        if let Some(mut transform) = transform
+            && let Some(value) = clip
+                .transform
+                .as_ref()
+                .and_then(|track| track.sample(clip_time))
+        {
+            *transform = value;
+        }
+        if let Some(mut hyper_sphere) = hyper_sphere
+            && let Some(radius) = clip
+                .radius
+                .as_ref()
+                .and_then(|track| track.sample(clip_time))
+        {
+            hyper_sphere.radius = radius;
+        }
+        // materials cant be changed in place, they are shared between entities with the same one
+        if let Some(material) = material
+            && let Some(base_color) = clip
+                .base_color
+                .as_ref()
+                .and_then(|track| track.sample(clip_time))
+            && base_color != material.base_color
+        {
+            commands.entity(entity).insert(Material { base_color });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AnimationPlugin, Interpolation, Keyframes};
+    use bevy::{
+        app::App,
+        time::{TimePlugin, TimeUpdateStrategy},
+    };
+    use render::ray_tracing::Color;
+    use std::time::Duration;
+    use transform::Vec4;
+
+    const TOLERANCE: f32 = 1e-5;
+
+    /// Moves 4 along x, fades from black towards orange and grows from a radius of 1 to 3 over one second
+    fn clip() -> AnimationClip {
+        AnimationClip::default()
+            .with_transform(Keyframes::new(
+                Interpolation::Linear,
+                [
+                    (0.0, Transform::IDENTITY),
+                    (1.0, Transform::translation(4.0, 0.0, 0.0, 0.0)),
+                ],
+            ))
+            .with_base_color(Keyframes::new(
+                Interpolation::Linear,
+                [
+                    (
+                        0.0,
+                        Color {
+                            red: 0.0,
+                            green: 0.0,
+                            blue: 0.0,
+                        },
+                    ),
+                    (
+                        1.0,
+                        Color {
+                            red: 1.0,
+                            green: 0.5,
+                            blue: 0.0,
+                        },
+                    ),
+                ],
+            ))
+            .with_radius(Keyframes::new(
+                Interpolation::Linear,
+                [(0.0, 1.0), (1.0, 3.0)],
+            ))
+    }
+
+    /// Plays the clip for `updates` frames of a quarter of a second, the first of which has no time pass,
+    /// and checks every track is at `clip_time`
+    fn assert_plays_to(repeat: RepeatMode, updates: usize, clip_time: f32) {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, AnimationPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                250,
+            )));
+        let entity = app
+            .world_mut()
+            .spawn((
+                HyperSphere::default(),
+                AnimationPlayer::new(Arc::new(clip())).with_repeat(repeat),
+            ))
+            .id();
+        for _ in 0..updates {
+            app.update();
+        }
+
+        let world = app.world();
+        let translation = world.get::<Transform>(entity).unwrap().translation_part();
+        assert!(
+            translation.abs_diff_eq(Vec4::new(4.0 * clip_time, 0.0, 0.0, 0.0), TOLERANCE),
+            "{repeat:?} {translation:?}"
+        );
+        let Color { red, green, blue } = world.get::<Material>(entity).unwrap().base_color;
+        assert!((red - clip_time).abs() < TOLERANCE, "{repeat:?} {red}");
+        assert!(
+            (green - clip_time * 0.5).abs() < TOLERANCE,
+            "{repeat:?} {green}"
+        );
+        assert!(blue.abs() < TOLERANCE);
+        let radius = world.get::<HyperSphere>(entity).unwrap().radius;
+        assert!(
+            (radius - (1.0 + 2.0 * clip_time)).abs() < TOLERANCE,
+            "{repeat:?} {radius}"
+        );
+    }
+
+    #[test]
+    fn once_holds_the_end() {
+        assert_plays_to(RepeatMode::Once, 3, 0.5);
+        assert_plays_to(RepeatMode::Once, 7, 1.0);
+    }
+
+    #[test]
+    fn loop_jumps_back_to_the_start() {
+        assert_plays_to(RepeatMode::Loop, 4, 0.75);
+        assert_plays_to(RepeatMode::Loop, 6, 0.25);
+    }
+
+    #[test]
+    fn ping_pong_plays_backwards() {
+        assert_plays_to(RepeatMode::PingPong, 6, 0.75);
+        assert_plays_to(RepeatMode::PingPong, 10, 0.25);
+    }
+
+    #[test]
+    fn unchanged_colours_keep_the_material() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, AnimationPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                250,
+            )));
+        let color = Color::default();
+        let clip = AnimationClip::default().with_base_color(Keyframes::new(
+            Interpolation::Linear,
+            [(0.0, color), (1.0, color)],
+        ));
+        let entity = app
+            .world_mut()
+            .spawn((
+                Material { base_color: color },
+                AnimationPlayer::new(Arc::new(clip)).with_repeat(RepeatMode::Loop),
+            ))
+            .id();
+        app.update();
+        let inserted = app
+            .world()
+            .entity(entity)
+            .get_change_ticks::<Material>()
+            .unwrap()
+            .changed;
+        for _ in 0..4 {
+            app.update();
+        }
+        let ticks = app
+            .world()
+            .entity(entity)
+            .get_change_ticks::<Material>()
+            .unwrap();
+        assert_eq!(ticks.changed, inserted);
+    }
+}
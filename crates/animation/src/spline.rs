@@ -0,0 +1,388 @@
+use std::fmt::Debug;
+use transform::{Point4, Rotor, Screw, Transform, Vec4};
+
+/// How many straight pieces each segment is measured with
+const SAMPLES_PER_SEGMENT: usize = 32;
+
+/// A path through 4D space, `t` goes from 0 at the start to [`Curve::segments`] at the end,
+/// moving on by one for each segment but not at any constant speed, see [`Path`] for that
+pub trait Curve: Debug + Send + Sync + 'static {
+    fn segments(&self) -> usize;
+
+    fn position(&self, t: f32) -> Point4;
+
+    /// The derivative of the position by `t`
+    fn velocity(&self, t: f32) -> Vec4;
+
+    /// The rotation along curves that have one
+    fn rotor(&self, _t: f32) -> Option<Rotor> {
+        None
+    }
+}
+
+/// The segment `t` is in and how far through it `t` is, `t` is clamped to the ends of the curve
+fn segment_at(t: f32, segments: usize) -> (usize, f32) {
+    let segment = (t.max(0.0) as usize).min(segments.saturating_sub(1));
+    (segment, (t - segment as f32).clamp(0.0, 1.0))
+}
+
+fn bezier_position([a, b, c, d]: [Point4; 4], t: f32) -> Point4 {
+    let s = 1.0 - t;
+    Point4::from_vector(
+        a.to_vector() * (s * s * s)
+            + b.to_vector() * (3.0 * s * s * t)
+            + c.to_vector() * (3.0 * s * t * t)
+            + d.to_vector() * (t * t * t),
+    )
+}
+
+fn bezier_velocity([a, b, c, d]: [Point4; 4], t: f32) -> Vec4 {
+    let s = 1.0 - t;
+    (b - a) * (3.0 * s * s) + (c - b) * (6.0 * s * t) + (d - c) * (3.0 * t * t)
+}
+
+/// Cubic Bézier segments, each one starts at its first point heading towards the second
+/// and ends at its last point coming from the third
+#[derive(Debug, Default, Clone)]
+pub struct CubicBezier {
+    pub segments: Vec<[Point4; 4]>,
+}
+
+impl CubicBezier {
+    /// Segments that each start where the last one ended, so every third point is passed through,
+    /// points that dont make up a whole segment at the end are left out
+    #[must_use]
+    pub fn from_control_points(points: &[Point4]) -> Self {
+        Self {
+            segments: points
+                .windows(4)
+                .step_by(3)
+                .map(|segment| [segment[0], segment[1], segment[2], segment[3]])
+                .collect(),
+        }
+    }
+}
+
+impl Curve for CubicBezier {
+    fn segments(&self) -> usize {
+        self.segments.len()
+    }
+
+    fn position(&self, t: f32) -> Point4 {
+        let (segment, t) = segment_at(t, self.segments.len());
+        self.segments
+            .get(segment)
+            .map_or(Point4::ORIGIN, |&segment| bezier_position(segment, t))
+    }
+
+    fn velocity(&self, t: f32) -> Vec4 {
+        let (segment, t) = segment_at(t, self.segments.len());
+        self.segments
+            .get(segment)
+            .map_or(Vec4::ZERO, |&segment| bezier_velocity(segment, t))
+    }
+}
+
+/// A smooth curve through every point, heading from each point parallel to the line between its neighbours
+#[derive(Debug, Default, Clone)]
+pub struct CatmullRom {
+    pub points: Vec<Point4>,
+}
+
+impl CatmullRom {
+    #[must_use]
+    pub fn new(points: impl IntoIterator<Item = Point4>) -> Self {
+        Self {
+            points: points.into_iter().collect(),
+        }
+    }
+
+    /// The same segment as a cubic Bézier
+    fn bezier_segment(&self, segment: usize) -> [Point4; 4] {
+        let start = self.points[segment];
+        let end = self.points[segment + 1];
+        // the ends carry on in a straight line
+        let before = segment
+            .checked_sub(1)
+            .map_or(start - (end - start), |before| self.points[before]);
+        let after = self
+            .points
+            .get(segment + 2)
+            .map_or(end + (end - start), |&after| after);
+        [
+            start,
+            start + (end - before) / 6.0,
+            end - (after - start) / 6.0,
+            end,
+        ]
+    }
+}
+
+impl Curve for CatmullRom {
+    fn segments(&self) -> usize {
+        self.points.len().saturating_sub(1)
+    }
+
+    fn position(&self, t: f32) -> Point4 {
+        if self.points.len() < 2 {
+            return self.points.first().copied().unwrap_or(Point4::ORIGIN);
+        }
+        let (segment, t) = segment_at(t, self.segments());
+        bezier_position(self.bezier_segment(segment), t)
+    }
+
+    fn velocity(&self, t: f32) -> Vec4 {
+        if self.points.len() < 2 {
+            return Vec4::ZERO;
+        }
+        let (segment, t) = segment_at(t, self.segments());
+        bezier_velocity(self.bezier_segment(segment), t)
+    }
+}
+
+/// The shortest screw motion from `from` to `to`, in the space of `from`
+fn difference(from: Transform, to: Transform) -> Screw {
+    let difference = from.inverse().then(to);
+    // the negated difference is the same transform
+    if difference.s < 0.0 {
+        (-difference).log()
+    } else {
+        difference.log()
+    }
+}
+
+/// A smooth curve through every key like a [`CatmullRom`], turning as well as moving,
+/// between the keys the transforms are interpolated with [`Transform::slerp`] rather than per coefficient
+#[derive(Debug, Default, Clone)]
+pub struct MotorSpline {
+    pub keys: Vec<Transform>,
+}
+
+impl MotorSpline {
+    #[must_use]
+    pub fn new(keys: impl IntoIterator<Item = Transform>) -> Self {
+        Self {
+            keys: keys.into_iter().collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn transform(&self, t: f32) -> Transform {
+        if self.keys.len() < 2 {
+            return self.keys.first().copied().unwrap_or(Transform::IDENTITY);
+        }
+        let (segment, t) = segment_at(t, self.segments());
+        let [a, b, c, d] = self.bezier_segment(segment);
+        // de Casteljau's algorithm, with every straight line swapped for a screw motion
+        let (ab, bc, cd) = (a.slerp(b, t), b.slerp(c, t), c.slerp(d, t));
+        ab.slerp(bc, t).slerp(bc.slerp(cd, t), t)
+    }
+
+    fn bezier_segment(&self, segment: usize) -> [Transform; 4] {
+        let start = self.keys[segment];
+        let end = self.keys[segment + 1];
+        // the same tangents as a Catmull-Rom curve, measured from the key they leave from
+        let forwards = difference(start, end);
+        let start_tangent = segment
+            .checked_sub(1)
+            .map_or(forwards.scaled(2.0), |before| {
+                forwards - difference(start, self.keys[before])
+            });
+        let backwards = difference(end, start);
+        let end_tangent = self
+            .keys
+            .get(segment + 2)
+            .map_or(backwards.scaled(-2.0), |&after| {
+                difference(end, after) - backwards
+            });
+        [
+            start,
+            start.then(Transform::exp(start_tangent.scaled(1.0 / 6.0))),
+            end.then(Transform::exp(end_tangent.scaled(-1.0 / 6.0))),
+            end,
+        ]
+    }
+}
+
+impl Curve for MotorSpline {
+    fn segments(&self) -> usize {
+        self.keys.len().saturating_sub(1)
+    }
+
+    fn position(&self, t: f32) -> Point4 {
+        Point4::from_vector(self.transform(t).translation_part())
+    }
+
+    fn velocity(&self, t: f32) -> Vec4 {
+        // slerping has no simple derivative
+        let step = 1e-3;
+        let (before, after) = ((t - step).max(0.0), (t + step).min(self.segments() as f32));
+        if after <= before {
+            return Vec4::ZERO;
+        }
+        (self.position(after) - self.position(before)) / (after - before)
+    }
+
+    fn rotor(&self, t: f32) -> Option<Rotor> {
+        Some(self.transform(t).rotor_part())
+    }
+}
+
+/// A [`Curve`] measured by the distance along it, so it can be moved along at a constant speed
+#[derive(Debug)]
+pub struct Path {
+    curve: Box<dyn Curve>,
+    /// The distance to evenly spaced values of `t`, [`SAMPLES_PER_SEGMENT`] of them for each segment
+    distances: Vec<f32>,
+}
+
+impl Path {
+    #[must_use]
+    pub fn new(curve: impl Curve) -> Self {
+        let samples = curve.segments() * SAMPLES_PER_SEGMENT;
+        let mut distances = Vec::with_capacity(samples + 1);
+        let mut distance = 0.0;
+        let mut previous = curve.position(0.0);
+        distances.push(distance);
+        for sample in 1..=samples {
+            let position = curve.position(sample as f32 / SAMPLES_PER_SEGMENT as f32);
+            distance += position.distance(previous);
+            distances.push(distance);
+            previous = position;
+        }
+        Self {
+            curve: Box::new(curve),
+            distances,
+        }
+    }
+
+    #[must_use]
+    pub fn curve(&self) -> &dyn Curve {
+        &*self.curve
+    }
+
+    #[must_use]
+    pub fn length(&self) -> f32 {
+        self.distances.last().copied().unwrap_or_default()
+    }
+
+    /// The `t` of the curve `distance` along it, clamped to the ends
+    #[must_use]
+    pub fn parameter_at(&self, distance: f32) -> f32 {
+        let next = self.distances.partition_point(|&sample| sample <= distance);
+        let Some(previous) = next.checked_sub(1) else {
+            return 0.0;
+        };
+        let Some(&end) = self.distances.get(next) else {
+            return self.curve.segments() as f32;
+        };
+        let start = self.distances[previous];
+        (previous as f32 + (distance - start) / (end - start)) / SAMPLES_PER_SEGMENT as f32
+    }
+
+    #[must_use]
+    pub fn position_at(&self, distance: f32) -> Point4 {
+        self.curve.position(self.parameter_at(distance))
+    }
+
+    /// The unit direction the curve heads in, zero where it comes to a stop
+    #[must_use]
+    pub fn direction_at(&self, distance: f32) -> Vec4 {
+        self.curve
+            .velocity(self.parameter_at(distance))
+            .normalised_or_zero()
+    }
+
+    #[must_use]
+    pub fn rotor_at(&self, distance: f32) -> Option<Rotor> {
+        self.curve.rotor(self.parameter_at(distance))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f32 = 1e-4;
+
+    fn points() -> [Point4; 4] {
+        [
+            Point4::new(0.0, 0.0, 0.0, 0.0),
+            Point4::new(1.0, 2.0, 0.0, -1.0),
+            Point4::new(3.0, 1.0, 2.0, 0.0),
+            Point4::new(4.0, -1.0, 1.0, 2.0),
+        ]
+    }
+
+    #[test]
+    fn curves_start_and_end_on_their_points() {
+        let points = points();
+        let bezier = CubicBezier::from_control_points(&points);
+        assert_eq!(bezier.segments(), 1);
+        assert!(bezier.position(0.0).abs_diff_eq(points[0], TOLERANCE));
+        assert!(bezier.position(1.0).abs_diff_eq(points[3], TOLERANCE));
+
+        let catmull_rom = CatmullRom::new(points);
+        assert_eq!(catmull_rom.segments(), 3);
+        for (t, point) in points.into_iter().enumerate() {
+            let position = catmull_rom.position(t as f32);
+            assert!(position.abs_diff_eq(point, TOLERANCE), "{t} {position:?}");
+        }
+    }
+
+    #[test]
+    fn motor_splines_pass_through_every_key() {
+        let keys = [
+            Transform::IDENTITY,
+            Transform::from_translation_rotor(
+                Vec4::new(2.0, 1.0, 0.0, 0.0),
+                Rotor::rotation_xy(1.0),
+            ),
+            Transform::from_translation_rotor(
+                Vec4::new(3.0, -1.0, 2.0, 1.0),
+                Rotor::rotation_zw(-0.5).then(Rotor::rotation_xz(2.0)),
+            ),
+        ];
+        let spline = MotorSpline::new(keys);
+        assert_eq!(spline.segments(), 2);
+        for (t, key) in keys.into_iter().enumerate() {
+            let transform = spline.transform(t as f32);
+            assert!(transform.approx_eq(key, TOLERANCE), "{t} {transform:?}");
+            let rotor = spline.rotor(t as f32).unwrap();
+            assert!(
+                rotor.approx_eq(key.rotor_part(), TOLERANCE),
+                "{t} {rotor:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parameters_grow_with_distance() {
+        let path = Path::new(CatmullRom::new(points()));
+        assert_eq!(path.parameter_at(-1.0), 0.0);
+        assert_eq!(path.parameter_at(path.length() + 1.0), 3.0);
+        let steps = 1000;
+        let mut previous = path.parameter_at(0.0);
+        let mut previous_position = path.position_at(0.0);
+        for step in 1..=steps {
+            let distance = path.length() * step as f32 / steps as f32;
+            let t = path.parameter_at(distance);
+            assert!(t >= previous, "{distance} {t} {previous}");
+            // evenly spaced distances are close to evenly spaced along the curve, the table is only piecewise linear
+            let position = path.position_at(distance);
+            let moved = position.distance(previous_position);
+            assert!(
+                (moved - path.length() / steps as f32).abs()
+                    < 0.05 * path.length() / steps as f32 + TOLERANCE,
+                "{distance} {moved}"
+            );
+            previous = t;
+            previous_position = position;
+        }
+        assert!((previous - 3.0).abs() < TOLERANCE);
+        assert!(
+            path.position_at(path.length())
+                .abs_diff_eq(points()[3], TOLERANCE)
+        );
+    }
+}
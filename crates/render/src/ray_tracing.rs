@@ -4,7 +4,7 @@ use bevy::{
     ecs::{
         change_detection::DetectChanges,
         entity::Entity,
-        query::{Added, Changed},
+        query::{Added, Changed, With},
         removal_detection::RemovedComponents,
         resource::Resource,
         system::{Query, Res, ResMut},
@@ -14,7 +14,9 @@ use bevy::{
 use bytemuck::{Pod, Zeroable};
 use result_texture::ResultTexture;
 use std::{mem::offset_of, num::NonZero};
-use transform::GlobalTransform;
+use transform::{
+    GlobalGridCell, GlobalScale, GlobalTransform, GridCell, GridSettings, Point4, Vec4,
+};
 use wgpu::util::DeviceExt;
 
 mod camera;
@@ -356,14 +358,14 @@ fn camera_upload(
         || camera.is_changed()
         || main_camera.is_changed()
     {
-        let transform = transform.0;
-        let rotation = transform.rotor_part();
+        // the camera is the floating origin, so its transform is already relative to the cell everything is rebased to
+        let transform = *transform;
 
-        let position = transform.transform((0.0, 0.0, 0.0, 0.0)).into();
-        let x = rotation.rotate((1.0, 0.0, 0.0, 0.0)).into();
-        let y = rotation.rotate((0.0, 1.0, 0.0, 0.0)).into();
-        let z = rotation.rotate((0.0, 0.0, 1.0, 0.0)).into();
-        let w = rotation.rotate((0.0, 0.0, 0.0, 1.0)).into();
+        let position = (transform * Point4::ORIGIN).into();
+        let x = (transform * Vec4::X).into();
+        let y = (transform * Vec4::Y).into();
+        let z = (transform * Vec4::Z).into();
+        let w = (transform * Vec4::W).into();
 
         let aspect = state.surface_config.width as f32 / state.surface_config.height as f32;
 
@@ -454,13 +456,42 @@ fn material_upload(
     }
 }
 
+/// The position of an entity relative to the centre of the `origin` cell,
+/// computed in `f64` so it stays precise when both cells are far away from zero
+fn rebased_position(
+    transform: &GlobalTransform,
+    GlobalGridCell(grid_cell): GlobalGridCell,
+    origin: GridCell,
+    cell_size: f32,
+) -> Point4 {
+    let position = transform.transform(Point4::ORIGIN).as_dpoint4();
+    (position + grid_cell.offset_from(origin, cell_size)).as_point4()
+}
+
 fn hyper_spheres_upload(
     state: Res<RenderState>,
     mut ray_tracing: ResMut<RayTracing>,
-    hyper_spheres_: Query<(Ref<GlobalTransform>, Ref<MaterialId>, Ref<HyperSphere>)>,
+    grid_settings: Res<GridSettings>,
+    main_camera: Query<Ref<GlobalGridCell>, With<MainCamera>>,
+    hyper_spheres_: Query<(
+        Ref<GlobalTransform>,
+        Ref<GlobalScale>,
+        Ref<GlobalGridCell>,
+        Ref<MaterialId>,
+        Ref<HyperSphere>,
+    )>,
     added_hyper_spheres: Query<(), Added<HyperSphere>>,
     mut removed_hyper_spheres: RemovedComponents<HyperSphere>,
 ) {
+    // every position is relative to the cell of the camera, so they all move when it does,
+    // without exactly one main camera they are relative to the zero cell instead
+    let (origin, origin_changed) = match main_camera.single() {
+        Ok(origin) => (origin.0, origin.is_changed()),
+        Err(_) => (GridCell::ZERO, false),
+    };
+    let origin_changed = origin_changed || grid_settings.is_changed();
+    let cell_size = grid_settings.cell_size;
+
     let was_removed = !removed_hyper_spheres.is_empty();
     if was_removed {
         removed_hyper_spheres.clear();
@@ -495,11 +526,13 @@ fn hyper_spheres_upload(
                 NonZero::new(required_space).unwrap(),
             )
             .unwrap();
-        for (index, (transform, material, hyper_sphere)) in hyper_spheres.enumerate() {
+        for (index, (transform, scale, grid_cell, material, hyper_sphere)) in
+            hyper_spheres.enumerate()
+        {
             let offset = index * size_of::<GpuHyperSphere>();
-            let position = transform.0.transform((0.0, 0.0, 0.0, 0.0)).into();
+            let position = rebased_position(&transform, *grid_cell, origin, cell_size).into();
             let material_id = material.0;
-            let HyperSphere { radius } = *hyper_sphere;
+            let radius = hyper_sphere.radius * scale.0.max_factor();
             let gpu_hyper_sphere = GpuHyperSphere {
                 position,
                 material_id,
@@ -510,17 +543,22 @@ fn hyper_spheres_upload(
                 .copy_from_slice(bytemuck::bytes_of(&gpu_hyper_sphere));
         }
     } else {
-        for (index, (transform, material, hyper_sphere)) in hyper_spheres.enumerate() {
+        for (index, (transform, scale, grid_cell, material, hyper_sphere)) in
+            hyper_spheres.enumerate()
+        {
             if was_removed
+                || origin_changed
                 || transform.is_changed()
+                || scale.is_changed()
+                || grid_cell.is_changed()
                 || material.is_changed()
                 || hyper_sphere.is_changed()
             {
                 let offset = index as wgpu::BufferAddress
                     * size_of::<GpuHyperSphere>() as wgpu::BufferAddress;
-                let position = transform.0.transform((0.0, 0.0, 0.0, 0.0)).into();
+                let position = rebased_position(&transform, *grid_cell, origin, cell_size).into();
                 let material_id = material.0;
-                let HyperSphere { radius } = *hyper_sphere;
+                let radius = hyper_sphere.radius * scale.0.max_factor();
                 let gpu_hyper_sphere = GpuHyperSphere {
                     position,
                     material_id,
@@ -636,3 +674,25 @@ fn ray_trace(
         state.queue.submit(std::iter::once(encoder.finish()));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use transform::Transform;
+
+    #[test]
+    fn rebased_positions_stay_precise_far_from_zero() {
+        let cell_size = 1000.0;
+        let far = GridCell::new(1 << 40, -(1 << 40), 0, 7);
+        let transform = GlobalTransform::from(Transform::translation(0.25, -0.5, 1.0, 0.0));
+
+        // in the cell of the camera only the translation is left
+        let position = rebased_position(&transform, GlobalGridCell(far), far, cell_size);
+        assert_eq!(position, Point4::new(0.25, -0.5, 1.0, 0.0));
+
+        // a neighbouring cell is a whole cell away, without losing the fraction
+        let origin = far + GridCell::new(1, 0, 0, 0);
+        let position = rebased_position(&transform, GlobalGridCell(far), origin, cell_size);
+        assert_eq!(position, Point4::new(-999.75, -0.5, 1.0, 0.0));
+    }
+}
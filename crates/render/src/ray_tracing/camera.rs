@@ -6,6 +6,7 @@ use bytemuck::{Pod, Zeroable};
 use transform::Transform;
 
 #[derive(Component, Reflect, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[reflect(Default, Clone)]
 #[require(Transform)]
 pub struct Camera {
@@ -7,9 +7,11 @@ use bytemuck::{Pod, Zeroable};
 use transform::Transform;
 
 #[derive(Component, Reflect, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[reflect(Default, Clone)]
 #[require(Transform, Material)]
 pub struct HyperSphere {
+    /// Multiplied by the largest factor of the [`transform::GlobalScale`]
     pub radius: f32,
 }
 
@@ -9,6 +9,7 @@ use bevy::{
 use bytemuck::{Pod, Zeroable};
 
 #[derive(Reflect, Debug, Clone, Copy, PartialEq, Zeroable, Pod)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[reflect(Default, Clone)]
 #[repr(C)]
 pub struct Color {
@@ -28,6 +29,7 @@ impl Default for Color {
 }
 
 #[derive(Component, Reflect, Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
 #[component(
     immutable,
     on_insert = material_insert,
@@ -49,10 +51,14 @@ pub(super) struct MaterialAllocator {
 
 fn material_insert(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
     let material = *world.get::<Material>(entity).unwrap();
+    // without the renderer, like in headless apps, there is nothing to allocate
+    let Some(mut allocator) = world.get_resource_mut::<MaterialAllocator>() else {
+        return;
+    };
     let MaterialAllocator {
         materials,
         free_start,
-    } = &mut *world.resource_mut::<MaterialAllocator>();
+    } = &mut *allocator;
 
     if let Some(id) = materials
         .iter()
@@ -77,6 +83,9 @@ fn material_insert(mut world: DeferredWorld, HookContext { entity, .. }: HookCon
 }
 
 fn material_replace(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    if !world.contains_resource::<MaterialAllocator>() {
+        return;
+    }
     let id = world.get::<MaterialId>(entity).unwrap().0 as usize;
     let MaterialAllocator {
         materials,
@@ -0,0 +1,110 @@
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use transform::{Point4, Rotor, Transform};
+
+const COUNT: usize = 4096;
+
+/// Deterministic transforms that rotate in every plane, so none of the products can be skipped
+fn transforms() -> Vec<Transform> {
+    (0..COUNT)
+        .map(|i| {
+            let t = i as f32 * 0.001;
+            Transform::translation(t, -2.0 * t, 0.5, 3.0 * t)
+                .then(Transform::rotation_xy(t + 0.1))
+                .then(Transform::rotation_zw(0.7 - t))
+                .then(Transform::rotation_xw(0.3 * t))
+                .then(Transform::rotation_yz(t * t))
+        })
+        .collect()
+}
+
+fn points() -> Vec<Point4> {
+    (0..COUNT)
+        .map(|i| {
+            let t = i as f32;
+            Point4::new(t.sin(), t.cos(), 0.01 * t, -t)
+        })
+        .collect()
+}
+
+fn products(c: &mut Criterion) {
+    let transforms = transforms();
+    let rotors = transforms
+        .iter()
+        .map(|t| t.rotor_part())
+        .collect::<Vec<_>>();
+    let points = points();
+
+    let mut group = c.benchmark_group("compose");
+    group.bench_function(BenchmarkId::new("transform", "scalar"), |b| {
+        b.iter(|| {
+            transforms
+                .windows(2)
+                .map(|pair| black_box(pair[0]).then_scalar(pair[1]))
+                .fold(Transform::IDENTITY, |_, t| black_box(t))
+        })
+    });
+    group.bench_function(BenchmarkId::new("transform", "simd"), |b| {
+        b.iter(|| {
+            transforms
+                .windows(2)
+                .map(|pair| black_box(pair[0]).then(pair[1]))
+                .fold(Transform::IDENTITY, |_, t| black_box(t))
+        })
+    });
+    group.bench_function(BenchmarkId::new("rotor", "scalar"), |b| {
+        b.iter(|| {
+            rotors
+                .windows(2)
+                .map(|pair| black_box(pair[0]).then_scalar(pair[1]))
+                .fold(Rotor::IDENTITY, |_, r| black_box(r))
+        })
+    });
+    group.bench_function(BenchmarkId::new("rotor", "simd"), |b| {
+        b.iter(|| {
+            rotors
+                .windows(2)
+                .map(|pair| black_box(pair[0]).then(pair[1]))
+                .fold(Rotor::IDENTITY, |_, r| black_box(r))
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("apply");
+    group.bench_function("scalar", |b| {
+        b.iter(|| {
+            for (transform, &point) in transforms.iter().zip(&points) {
+                black_box(black_box(transform).transform_scalar(point));
+            }
+        })
+    });
+    group.bench_function("simd", |b| {
+        b.iter(|| {
+            for (transform, &point) in transforms.iter().zip(&points) {
+                black_box(black_box(transform).transform(point));
+            }
+        })
+    });
+    // the common case for culling, one transform applied to many points
+    let transform = transforms[COUNT / 2];
+    group.bench_function("one_transform_scalar", |b| {
+        let mut out = points.clone();
+        b.iter(|| {
+            for (out, &point) in out.iter_mut().zip(&points) {
+                *out = black_box(transform).transform_scalar(point);
+            }
+            black_box(&out);
+        })
+    });
+    group.bench_function("one_transform_slice", |b| {
+        let mut out = points.clone();
+        b.iter(|| {
+            out.copy_from_slice(&points);
+            black_box(transform).transform_points(&mut out);
+            black_box(&out);
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, products);
+criterion_main!(benches);
@@ -0,0 +1,169 @@
+use bevy::{
+    app::{App, Plugin, PostUpdate},
+    ecs::{
+        change_detection::DetectChanges,
+        entity::Entity,
+        hierarchy::ChildOf,
+        query::{Changed, Or, With, Without},
+        schedule::IntoScheduleConfigs,
+        system::Query,
+        world::Ref,
+    },
+};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use transform::{GlobalTransform, Transform, TransformPlugin};
+
+/// The propagation from before subtrees were skipped, every child walks up to its root every frame
+struct WalkUpPlugin;
+
+impl Plugin for WalkUpPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                normalise_transforms,
+                (
+                    compute_global_transform_parents,
+                    compute_global_transform_children,
+                ),
+            )
+                .chain(),
+        );
+    }
+}
+
+fn normalise_transforms(mut transforms: Query<&mut Transform, Changed<Transform>>) {
+    transforms
+        .par_iter_mut()
+        .for_each(|mut transform| *transform = transform.normalised());
+}
+
+fn compute_global_transform_parents(
+    mut global_transforms: Query<
+        (&Transform, &mut GlobalTransform),
+        (
+            Or<(Changed<Transform>, Changed<GlobalTransform>)>,
+            Without<ChildOf>,
+        ),
+    >,
+) {
+    global_transforms
+        .par_iter_mut()
+        .for_each(|(transform, mut global_transform)| {
+            *global_transform = GlobalTransform::Rigid(*transform);
+        })
+}
+
+fn compute_global_transform_children(
+    mut global_transforms: Query<(Entity, &mut GlobalTransform), With<ChildOf>>,
+    transforms: Query<(Option<Ref<Transform>>, Option<Ref<ChildOf>>)>,
+) {
+    global_transforms
+        .par_iter_mut()
+        .for_each(|(entity, mut global_transform)| {
+            let mut computed_transform = Transform::default();
+
+            let mut changed = global_transform.is_changed();
+            let mut next = entity;
+            loop {
+                let Ok((transform, child_of)) = transforms.get(next) else {
+                    unreachable!()
+                };
+
+                if let Some(transform) = transform {
+                    changed |= transform.is_changed();
+                    computed_transform = transform.then(computed_transform);
+                }
+
+                let Some(child_of) = child_of else {
+                    break;
+                };
+                changed |= child_of.is_changed();
+                next = child_of.0;
+            }
+
+            if changed {
+                *global_transform = GlobalTransform::Rigid(computed_transform.normalised());
+            }
+        })
+}
+
+/// `roots` trees where every entity has `branches` children, down to `depth` levels, returns the deepest entities
+fn spawn_trees(app: &mut App, roots: usize, branches: usize, depth: usize) -> Vec<Entity> {
+    let world = app.world_mut();
+    let mut level = (0..roots)
+        .map(|_| world.spawn(Transform::translation(1.0, 0.0, 0.0, 0.0)).id())
+        .collect::<Vec<_>>();
+    for _ in 1..depth {
+        level = level
+            .into_iter()
+            .flat_map(|parent| (0..branches).map(move |_| parent))
+            .map(|parent| {
+                world
+                    .spawn((Transform::rotation_xy(0.1), ChildOf(parent)))
+                    .id()
+            })
+            .collect();
+    }
+    level
+}
+
+fn app(incremental: bool) -> App {
+    let mut app = App::new();
+    if incremental {
+        app.add_plugins(TransformPlugin);
+    } else {
+        app.add_plugins(WalkUpPlugin);
+    }
+    app
+}
+
+fn propagation(c: &mut Criterion) {
+    // (roots, branches, depth), a wide and a deep hierarchy of a few thousand entities
+    let shapes = [("wide", (16, 4, 5)), ("deep", (4, 1, 256))];
+
+    for (name, (roots, branches, depth)) in shapes {
+        let mut group = c.benchmark_group(format!("propagation/{name}"));
+        for (approach, incremental) in [("walk_up", false), ("incremental", true)] {
+            group.bench_function(BenchmarkId::new("unchanged", approach), |b| {
+                let mut app = app(incremental);
+                spawn_trees(&mut app, roots, branches, depth);
+                app.update();
+                b.iter(|| app.update());
+            });
+
+            group.bench_function(BenchmarkId::new("one_leaf_changed", approach), |b| {
+                let mut app = app(incremental);
+                let leaves = spawn_trees(&mut app, roots, branches, depth);
+                app.update();
+                b.iter(|| {
+                    let mut transform = app.world_mut().get_mut::<Transform>(leaves[0]).unwrap();
+                    *transform = transform.then(Transform::rotation_zw(0.01));
+                    app.update();
+                });
+            });
+
+            group.bench_function(BenchmarkId::new("roots_changed", approach), |b| {
+                let mut app = app(incremental);
+                spawn_trees(&mut app, roots, branches, depth);
+                app.update();
+                let roots = app
+                    .world_mut()
+                    .query_filtered::<Entity, Without<ChildOf>>()
+                    .iter(app.world())
+                    .collect::<Vec<_>>();
+                b.iter(|| {
+                    for &root in &roots {
+                        let mut transform = app.world_mut().get_mut::<Transform>(root).unwrap();
+                        *transform = transform.then(Transform::rotation_zw(0.01));
+                    }
+                    app.update();
+                });
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, propagation);
+criterion_main!(benches);
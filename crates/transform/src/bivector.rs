@@ -0,0 +1,121 @@
+use bevy::reflect::{Reflect, prelude::ReflectDefault};
+use bytemuck::{Pod, Zeroable};
+
+/// The generator of a [`Rotor`](crate::Rotor), `Rotor::exp(Bivector::rotation_xy(angle))` is the same rotation as `Rotor::rotation_xy(angle)`
+#[derive(Reflect, Debug, Default, Clone, Copy, Zeroable, Pod)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[repr(C)]
+pub struct Bivector {
+    pub e12: f32,
+    pub e13: f32,
+    pub e14: f32,
+    pub e23: f32,
+    pub e24: f32,
+    pub e34: f32,
+}
+
+impl Bivector {
+    pub const ZERO: Self = Self {
+        e12: 0.0,
+        e13: 0.0,
+        e14: 0.0,
+        e23: 0.0,
+        e24: 0.0,
+        e34: 0.0,
+    };
+
+    #[must_use]
+    pub const fn rotation_xy(angle: f32) -> Self {
+        Self {
+            e12: angle * 0.5,
+            ..Self::ZERO
+        }
+    }
+
+    #[must_use]
+    pub const fn rotation_xz(angle: f32) -> Self {
+        Self {
+            e13: angle * -0.5,
+            ..Self::ZERO
+        }
+    }
+
+    #[must_use]
+    pub const fn rotation_xw(angle: f32) -> Self {
+        Self {
+            e14: angle * 0.5,
+            ..Self::ZERO
+        }
+    }
+
+    #[must_use]
+    pub const fn rotation_yz(angle: f32) -> Self {
+        Self {
+            e23: angle * 0.5,
+            ..Self::ZERO
+        }
+    }
+
+    #[must_use]
+    pub const fn rotation_yw(angle: f32) -> Self {
+        Self {
+            e24: angle * -0.5,
+            ..Self::ZERO
+        }
+    }
+
+    #[must_use]
+    pub const fn rotation_zw(angle: f32) -> Self {
+        Self {
+            e34: angle * 0.5,
+            ..Self::ZERO
+        }
+    }
+
+    #[must_use]
+    pub const fn scaled(self, scale: f32) -> Self {
+        let Self {
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+        } = self;
+        Self {
+            e12: e12 * scale,
+            e13: e13 * scale,
+            e14: e14 * scale,
+            e23: e23 * scale,
+            e24: e24 * scale,
+            e34: e34 * scale,
+        }
+    }
+
+    #[must_use]
+    pub const fn magnitude_squared(self) -> f32 {
+        let Self {
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+        } = self;
+        e12 * e12 + e13 * e13 + e14 * e14 + e23 * e23 + e24 * e24 + e34 * e34
+    }
+
+    #[must_use]
+    pub fn magnitude(self) -> f32 {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Whether every component is within `max_abs_diff` of the matching component of `other`
+    #[must_use]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f32) -> bool {
+        let a: [f32; 6] = bytemuck::cast(self);
+        let b: [f32; 6] = bytemuck::cast(other);
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() <= max_abs_diff)
+    }
+}
@@ -0,0 +1,273 @@
+use crate::{
+    FixedStepTransforms, GlobalGridCell, GlobalScale, GlobalTransform, GridCell, GridSettings,
+    Reflection, Scale, Transform, TransformHelper,
+};
+use bevy::ecs::{
+    entity::Entity,
+    hierarchy::ChildOf,
+    system::{EntityCommands, SystemState},
+    world::{EntityWorldMut, World},
+};
+
+/// Changes the parent of an entity without moving it, by updating its [`Transform`], [`Reflection`], [`Scale`] and [`GridCell`],
+/// when the hierarchy is malformed the entity is reparented as is.
+/// Entities with [`crate::InterpolateTransform`] keep the pose after the last fixed timestep rather than the interpolated one,
+/// and start interpolating again from there
+pub trait ReparentInPlace {
+    fn set_parent_in_place(&mut self, parent: Entity) -> &mut Self;
+
+    fn remove_parent_in_place(&mut self) -> &mut Self;
+}
+
+impl ReparentInPlace for EntityWorldMut<'_> {
+    fn set_parent_in_place(&mut self, parent: Entity) -> &mut Self {
+        let entity = self.id();
+        if let Some(local) =
+            self.world_scope(|world| local_relative_to(world, entity, Some(parent)))
+        {
+            insert_local(self, local);
+        }
+        self.insert(ChildOf(parent))
+    }
+
+    fn remove_parent_in_place(&mut self) -> &mut Self {
+        let entity = self.id();
+        if let Some(local) = self.world_scope(|world| local_relative_to(world, entity, None)) {
+            insert_local(self, local);
+        }
+        self.remove::<ChildOf>()
+    }
+}
+
+impl ReparentInPlace for EntityCommands<'_> {
+    fn set_parent_in_place(&mut self, parent: Entity) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            entity.set_parent_in_place(parent);
+        })
+    }
+
+    fn remove_parent_in_place(&mut self) -> &mut Self {
+        self.queue(|mut entity: EntityWorldMut| {
+            entity.remove_parent_in_place();
+        })
+    }
+}
+
+/// The local transform and scale that keep `entity` where it is under `parent`,
+/// along with the cell they are relative to
+fn local_relative_to(
+    world: &mut World,
+    entity: Entity,
+    parent: Option<Entity>,
+) -> Option<(GlobalTransform, Scale, GridCell)> {
+    let cell_size = world.resource::<GridSettings>().cell_size;
+    let mut helper = SystemState::<TransformHelper>::new(world);
+    let helper = helper.get(world);
+
+    // the transform is set from the fixed timestep, so the interpolated pose would move it back by up to a step
+    let (global_transform, GlobalScale(global_scale)) = helper.compute_fixed_step(entity).ok()?;
+    let GlobalGridCell(grid_cell) = helper.compute_global_grid_cell(entity).ok()?;
+    let (parent_transform, GlobalScale(parent_scale), GlobalGridCell(parent_cell)) = match parent {
+        Some(parent) => {
+            let (transform, scale) = helper.compute_fixed_step(parent).ok()?;
+            (
+                transform,
+                scale,
+                helper.compute_global_grid_cell(parent).ok()?,
+            )
+        }
+        None => (
+            GlobalTransform::IDENTITY,
+            GlobalScale(Scale::ONE),
+            GlobalGridCell(grid_cell),
+        ),
+    };
+
+    // moves the global transform into the cell of the parent
+    let offset = grid_cell.offset_from(parent_cell, cell_size).as_vec4();
+    let global_transform = GlobalTransform::from(Transform::translation(
+        offset.x, offset.y, offset.z, offset.w,
+    ))
+    .then(global_transform);
+
+    // undoes the parent the same way propagation applies it
+    let transform = parent_transform
+        .inverse()
+        .then(global_transform)
+        .with_scaled_translation(parent_scale.inverse());
+    Some((
+        transform,
+        parent_scale.inverse().then(global_scale),
+        parent_cell,
+    ))
+}
+
+fn insert_local(
+    entity: &mut EntityWorldMut,
+    (transform, scale, grid_cell): (GlobalTransform, Scale, GridCell),
+) {
+    // keeps an existing reflection when the local transform is still mirrored
+    match (transform, entity.get::<Reflection>().copied()) {
+        (GlobalTransform::Rigid(transform), _) => {
+            entity.insert(transform).remove::<Reflection>();
+        }
+        (GlobalTransform::Mirrored(mirrored), Some(reflection)) => {
+            entity.insert(mirrored.then_reflection(reflection.inverse()));
+        }
+        (GlobalTransform::Mirrored(mirrored), None) => {
+            entity.insert((
+                mirrored.then_reflection(Reflection::MIRROR_X),
+                Reflection::MIRROR_X,
+            ));
+        }
+    }
+
+    // the old poses are relative to the old parent
+    let transform = entity.get::<Transform>().copied().unwrap_or_default();
+    if let Some(mut poses) = entity.get_mut::<FixedStepTransforms>() {
+        *poses = FixedStepTransforms {
+            previous: transform,
+            current: transform,
+            interpolated: transform,
+        };
+    }
+
+    if scale == Scale::ONE {
+        entity.remove::<Scale>();
+    } else {
+        entity.insert(scale);
+    }
+
+    // only matters once the entity is a root again, but it would be stale by then
+    if grid_cell != GridCell::ZERO || entity.contains::<GridCell>() {
+        entity.insert(grid_cell);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{InterpolateTransform, Point4, Rotor, TransformPlugin, Vec4};
+    use bevy::{app::App, ecs::bundle::Bundle};
+
+    const TOLERANCE: f32 = 1e-3;
+
+    /// Where a few points of the entity end up relative to the centre of the zero cell
+    fn world_points(app: &App, entity: Entity) -> [Point4; 3] {
+        let cell_size = app.world().resource::<GridSettings>().cell_size;
+        let GlobalGridCell(cell) = *app.world().get::<GlobalGridCell>(entity).unwrap();
+        let offset = cell.offset_from(GridCell::ZERO, cell_size).as_vec4();
+        let global_transform = *app.world().get::<GlobalTransform>(entity).unwrap();
+        [
+            Point4::ORIGIN,
+            Point4::new(1.0, 0.0, 0.0, 0.0),
+            Point4::new(0.0, 2.0, -1.0, 0.5),
+        ]
+        .map(|point| global_transform * point + offset)
+    }
+
+    fn assert_unmoved(before: [Point4; 3], after: [Point4; 3]) {
+        for (before, after) in before.into_iter().zip(after) {
+            assert!(before.abs_diff_eq(after, TOLERANCE), "{before:?} {after:?}");
+        }
+    }
+
+    fn reparent_in_place(parent: impl Bundle) {
+        let mut app = App::new();
+        app.add_plugins(TransformPlugin);
+        let parent = app.world_mut().spawn(parent).id();
+        let entity = app
+            .world_mut()
+            .spawn((
+                Transform::from_translation_rotor(
+                    Vec4::new(3.0, -1.0, 0.5, 2.0),
+                    Rotor::rotation_yw(0.4),
+                ),
+                Scale::Uniform(1.5),
+            ))
+            .id();
+        app.update();
+        let before = world_points(&app, entity);
+
+        app.world_mut()
+            .entity_mut(entity)
+            .set_parent_in_place(parent);
+        app.update();
+        assert_eq!(app.world().get::<ChildOf>(entity), Some(&ChildOf(parent)));
+        assert_unmoved(before, world_points(&app, entity));
+
+        app.world_mut().entity_mut(entity).remove_parent_in_place();
+        app.update();
+        assert!(app.world().get::<ChildOf>(entity).is_none());
+        assert_unmoved(before, world_points(&app, entity));
+    }
+
+    #[test]
+    fn scaled_parent() {
+        reparent_in_place((
+            Transform::from_translation_rotor(
+                Vec4::new(-2.0, 4.0, 1.0, 0.0),
+                Rotor::rotation_xz(1.1),
+            ),
+            Scale::PerAxis(Vec4::new(2.0, 0.5, 3.0, 1.0)),
+        ));
+    }
+
+    #[test]
+    fn mirrored_parent() {
+        reparent_in_place((
+            Transform::from_translation_rotor(
+                Vec4::new(1.0, 1.0, 0.0, 0.0),
+                Rotor::rotation_xy(0.3),
+            ),
+            Reflection::MIRROR_X,
+        ));
+    }
+
+    #[test]
+    fn parent_in_another_cell() {
+        reparent_in_place((
+            Transform::translation(10.0, 0.0, 0.0, 0.0),
+            GridCell::new(1, 0, -2, 0),
+        ));
+    }
+
+    #[test]
+    fn interpolated_entities_keep_their_fixed_step_pose() {
+        let mut app = App::new();
+        app.add_plugins(TransformPlugin);
+        let parent = app
+            .world_mut()
+            .spawn(Transform::translation(10.0, 0.0, 0.0, 0.0))
+            .id();
+        let current = Transform::translation(2.0, 0.0, 0.0, 0.0);
+        let entity = app
+            .world_mut()
+            .spawn((
+                current,
+                InterpolateTransform,
+                FixedStepTransforms {
+                    previous: Transform::IDENTITY,
+                    current,
+                    interpolated: Transform::translation(1.0, 0.0, 0.0, 0.0),
+                },
+            ))
+            .id();
+
+        app.world_mut()
+            .entity_mut(entity)
+            .set_parent_in_place(parent);
+        let expected = Transform::translation(-8.0, 0.0, 0.0, 0.0);
+        let entity = app.world().entity(entity);
+        assert!(
+            entity
+                .get::<Transform>()
+                .unwrap()
+                .approx_eq(expected, TOLERANCE)
+        );
+        let poses = entity.get::<FixedStepTransforms>().unwrap();
+        for pose in [poses.previous, poses.current, poses.interpolated] {
+            assert!(pose.approx_eq(expected, TOLERANCE), "{pose:?}");
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use crate::{GlobalScale, GridSettings, Point4, Transform, TransformHelper, Vec4};
+use bevy::{
+    ecs::{
+        component::Component,
+        entity::Entity,
+        hierarchy::ChildOf,
+        system::{Local, ParamSet, Query, Res},
+    },
+    reflect::Reflect,
+    time::Time,
+};
+use std::f32::consts::TAU;
+
+/// Turns the forward (x) axis of an entity towards another entity like [`Transform::look_at`],
+/// `up` and `over` are in the space of its parent, nothing happens while the target doesnt exist
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Clone)]
+#[require(Transform)]
+pub struct LookAt4 {
+    pub target: Entity,
+    pub up: Vec4,
+    pub over: Vec4,
+}
+
+impl LookAt4 {
+    #[must_use]
+    pub const fn new(target: Entity) -> Self {
+        Self {
+            target,
+            up: Vec4::Y,
+            over: Vec4::W,
+        }
+    }
+}
+
+/// Moves an entity to `offset` from another entity, the offset is in the space of the target so it turns
+/// and scales with it the same as the translation of a child would
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Clone)]
+#[require(Transform)]
+pub struct Follow {
+    pub target: Entity,
+    pub offset: Vec4,
+    /// The gap left to the target shrinks by a factor of e every `damping` seconds, zero keeps it exactly in place
+    pub damping: f32,
+}
+
+/// Moves an entity around a circle, starting in the direction of `plane[0]` from `center` and heading towards `plane[1]`,
+/// the centre and directions are in the space of its parent
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Clone)]
+#[require(Transform)]
+pub struct Orbit4 {
+    pub center: Point4,
+    pub plane: [Vec4; 2],
+    pub radius: f32,
+    /// Seconds for one whole turn, a negative period goes around the other way
+    pub period: f32,
+}
+
+impl Orbit4 {
+    /// Where the entity is `elapsed` seconds after starting
+    #[must_use]
+    pub fn position(&self, elapsed: f64) -> Point4 {
+        let turns = if self.period == 0.0 {
+            0.0
+        } else {
+            // f32 seconds lose precision long before the fraction of a turn would
+            (elapsed / self.period as f64).fract() as f32
+        };
+        let [start, towards] = self.plane;
+        let start = start.normalised_or_zero();
+        let towards = (towards - start * towards.dot(start)).normalised_or_zero();
+        let (sin, cos) = (turns * TAU).sin_cos();
+        self.center + (start * cos + towards * sin) * self.radius
+    }
+}
+
+/// Where `offset` from `target`, scaled by the [`GlobalScale`] of the target, is in the space the [`Transform`] of `entity` is relative to,
+/// `None` if either of them isnt in a well formed hierarchy
+fn target_point(
+    helper: &TransformHelper,
+    settings: &GridSettings,
+    entity: Entity,
+    parent: Option<&ChildOf>,
+    target: Entity,
+    offset: Vec4,
+) -> Option<Point4> {
+    let (target_transform, GlobalScale(target_scale)) = helper.compute(target).ok()?;
+    let target_cell = helper.compute_global_grid_cell(target).ok()?.0;
+    let cell = helper.compute_global_grid_cell(entity).ok()?.0;
+    let point = target_transform.transform(Point4::from_vector(target_scale.scale(offset)))
+        + target_cell.offset_from(cell, settings.cell_size).as_vec4();
+
+    let Some(parent) = parent else {
+        return Some(point);
+    };
+    // undoes what propagation does to the translations of the children of `parent`
+    let (parent_transform, GlobalScale(parent_scale)) = helper.compute(parent.0).ok()?;
+    let local = parent_transform.inverse().transform(point).to_vector();
+    Some(Point4::from_vector(parent_scale.inverse().scale(local)))
+}
+
+pub(crate) fn orbit(time: Res<Time>, mut orbits: Query<(&Orbit4, &mut Transform)>) {
+    let elapsed = time.elapsed_secs_f64();
+    orbits.par_iter_mut().for_each(|(orbit, mut transform)| {
+        *transform = transform.with_translation(orbit.position(elapsed).to_vector());
+    });
+}
+
+/// Targets are found before anything moves, so an entity following one that is itself constrained
+/// follows where it was at the end of `Update`
+pub(crate) fn follow(
+    time: Res<Time>,
+    settings: Res<GridSettings>,
+    followers: Query<(Entity, &Follow, Option<&ChildOf>)>,
+    mut transforms: ParamSet<(TransformHelper, Query<&mut Transform>)>,
+    mut targets: Local<Vec<(Entity, Point4)>>,
+) {
+    let helper = transforms.p0();
+    targets.extend(followers.iter().filter_map(|(entity, follow, child_of)| {
+        let point = target_point(
+            &helper,
+            &settings,
+            entity,
+            child_of,
+            follow.target,
+            follow.offset,
+        )?;
+        Some((entity, point))
+    }));
+
+    let mut transforms = transforms.p1();
+    for (entity, target) in targets.drain(..) {
+        let (Ok(mut transform), Ok((_, follow, _))) =
+            (transforms.get_mut(entity), followers.get(entity))
+        else {
+            continue;
+        };
+        let fraction = if follow.damping > 0.0 {
+            1.0 - (-time.delta_secs() / follow.damping).exp()
+        } else {
+            1.0
+        };
+        let translation = transform
+            .translation_part()
+            .lerp(target.to_vector(), fraction);
+        *transform = transform.with_translation(translation);
+    }
+}
+
+pub(crate) fn look_at(
+    settings: Res<GridSettings>,
+    lookers: Query<(Entity, &LookAt4, Option<&ChildOf>)>,
+    mut transforms: ParamSet<(TransformHelper, Query<&mut Transform>)>,
+    mut targets: Local<Vec<(Entity, Point4)>>,
+) {
+    let helper = transforms.p0();
+    targets.extend(lookers.iter().filter_map(|(entity, look_at, child_of)| {
+        let point = target_point(
+            &helper,
+            &settings,
+            entity,
+            child_of,
+            look_at.target,
+            Vec4::ZERO,
+        )?;
+        Some((entity, point))
+    }));
+
+    let mut transforms = transforms.p1();
+    for (entity, target) in targets.drain(..) {
+        let (Ok(mut transform), Ok((_, look_at, _))) =
+            (transforms.get_mut(entity), lookers.get(entity))
+        else {
+            continue;
+        };
+        let eye = Point4::from_vector(transform.translation_part());
+        *transform = Transform::look_at(eye, target, look_at.up, look_at.over);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GlobalTransform, Scale, TransformPlugin};
+    use bevy::{
+        app::App,
+        time::{TimePlugin, TimeUpdateStrategy},
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn resolves_orbit_then_follow_then_look_at() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, TransformPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )));
+        let orbit = Orbit4 {
+            center: Point4::ORIGIN,
+            plane: [Vec4::X, Vec4::Z],
+            radius: 3.0,
+            period: 4.0,
+        };
+        let orbiter = app.world_mut().spawn(orbit).id();
+        let parent = app
+            .world_mut()
+            .spawn(Transform::translation(0.0, 0.0, 0.0, 5.0).then(Transform::rotation_xz(0.7)))
+            .id();
+        let offset = Vec4::new(0.0, 0.0, 1.0, 0.0);
+        let follower = app
+            .world_mut()
+            .spawn((
+                ChildOf(parent),
+                Follow {
+                    target: orbiter,
+                    offset,
+                    damping: 0.0,
+                },
+                LookAt4::new(orbiter),
+            ))
+            .id();
+
+        for _ in 0..10 {
+            app.update();
+            let world = app.world();
+            // the orbiter has already moved this frame by the time the follower is placed,
+            // and the follower is placed before it is turned towards the orbiter
+            let target = orbit.position(world.resource::<Time>().elapsed_secs_f64());
+            let orbiter_global = *world.get::<GlobalTransform>(orbiter).unwrap();
+            assert!((orbiter_global * Point4::ORIGIN).abs_diff_eq(target, 1e-4));
+
+            let follower_global = *world.get::<GlobalTransform>(follower).unwrap();
+            assert!(
+                (follower_global * Point4::ORIGIN).abs_diff_eq(target + offset, 1e-4),
+                "{:?} {:?}",
+                follower_global * Point4::ORIGIN,
+                target + offset
+            );
+            assert!((follower_global * Vec4::X).abs_diff_eq(-offset, 1e-4));
+        }
+    }
+
+    #[test]
+    fn offsets_scale_with_the_target() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, TransformPlugin));
+        let target = app
+            .world_mut()
+            .spawn((
+                Transform::translation(1.0, 2.0, 0.0, 0.0).then(Transform::rotation_xy(0.4)),
+                Scale::PerAxis(Vec4::new(2.0, 3.0, 1.0, 0.5)),
+            ))
+            .id();
+        // the same offset as a child of the target
+        let offset = Vec4::new(1.0, -1.0, 2.0, 4.0);
+        let child = app
+            .world_mut()
+            .spawn((
+                ChildOf(target),
+                Transform::IDENTITY.with_translation(offset),
+            ))
+            .id();
+        let follower = app
+            .world_mut()
+            .spawn(Follow {
+                target,
+                offset,
+                damping: 0.0,
+            })
+            .id();
+        app.update();
+
+        let world = app.world();
+        let expected = *world.get::<GlobalTransform>(child).unwrap() * Point4::ORIGIN;
+        let position = *world.get::<GlobalTransform>(follower).unwrap() * Point4::ORIGIN;
+        assert!(
+            position.abs_diff_eq(expected, 1e-4),
+            "{position:?} {expected:?}"
+        );
+    }
+}
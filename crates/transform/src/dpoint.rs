@@ -0,0 +1,140 @@
+use crate::{DVec4, Point4};
+use bevy::reflect::{Reflect, prelude::ReflectDefault};
+use bytemuck::{Pod, Zeroable};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// A position in 4D space with `f64` precision, for worlds too large for [`Point4`]
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Zeroable, Pod)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[repr(C)]
+pub struct DPoint4 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+impl DPoint4 {
+    pub const ORIGIN: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    #[must_use]
+    pub const fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// The point at the end of `offset` from the origin
+    #[must_use]
+    pub const fn from_vector(offset: DVec4) -> Self {
+        let DVec4 { x, y, z, w } = offset;
+        Self::new(x, y, z, w)
+    }
+
+    /// The offset of this point from the origin
+    #[must_use]
+    pub const fn to_vector(self) -> DVec4 {
+        let Self { x, y, z, w } = self;
+        DVec4::new(x, y, z, w)
+    }
+
+    #[must_use]
+    pub const fn distance_squared(self, other: Self) -> f64 {
+        let Self { x, y, z, w } = self;
+        let (x, y, z, w) = (x - other.x, y - other.y, z - other.z, w - other.w);
+        x * x + y * y + z * z + w * w
+    }
+
+    #[must_use]
+    pub fn distance(self, other: Self) -> f64 {
+        self.distance_squared(other).sqrt()
+    }
+
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
+
+    #[must_use]
+    pub const fn to_array(self) -> [f64; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Whether every component is within `max_abs_diff` of the matching component of `other`
+    #[must_use]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f64) -> bool {
+        let (a, b) = (self.to_array(), other.to_array());
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() <= max_abs_diff)
+    }
+
+    /// Rounds each component to the nearest `f32`
+    #[must_use]
+    pub const fn as_point4(self) -> Point4 {
+        Point4::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+}
+
+impl Add<DVec4> for DPoint4 {
+    type Output = Self;
+
+    fn add(self, offset: DVec4) -> Self {
+        Self::from_vector(self.to_vector() + offset)
+    }
+}
+
+impl AddAssign<DVec4> for DPoint4 {
+    fn add_assign(&mut self, offset: DVec4) {
+        *self = *self + offset;
+    }
+}
+
+impl Sub<DVec4> for DPoint4 {
+    type Output = Self;
+
+    fn sub(self, offset: DVec4) -> Self {
+        Self::from_vector(self.to_vector() - offset)
+    }
+}
+
+impl SubAssign<DVec4> for DPoint4 {
+    fn sub_assign(&mut self, offset: DVec4) {
+        *self = *self - offset;
+    }
+}
+
+impl Sub for DPoint4 {
+    type Output = DVec4;
+
+    fn sub(self, other: Self) -> DVec4 {
+        self.to_vector() - other.to_vector()
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for DPoint4 {
+    fn from((x, y, z, w): (f64, f64, f64, f64)) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+impl From<DPoint4> for (f64, f64, f64, f64) {
+    fn from(DPoint4 { x, y, z, w }: DPoint4) -> Self {
+        (x, y, z, w)
+    }
+}
+
+impl From<[f64; 4]> for DPoint4 {
+    fn from([x, y, z, w]: [f64; 4]) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+impl From<DPoint4> for [f64; 4] {
+    fn from(point: DPoint4) -> Self {
+        point.to_array()
+    }
+}
+
+impl From<Point4> for DPoint4 {
+    fn from(point: Point4) -> Self {
+        point.as_dpoint4()
+    }
+}
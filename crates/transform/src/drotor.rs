@@ -0,0 +1,397 @@
+use crate::{DPoint4, DVec4, Rotor};
+use bevy::{
+    math::DQuat,
+    reflect::{Reflect, prelude::ReflectDefault},
+};
+use bytemuck::{Pod, Zeroable};
+use std::ops::{Mul, MulAssign, Neg};
+
+/// A [`Rotor`] with `f64` precision
+#[derive(Reflect, Debug, Clone, Copy, Zeroable, Pod)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[repr(C)]
+pub struct DRotor {
+    pub s: f64,
+    pub e12: f64,
+    pub e13: f64,
+    pub e14: f64,
+    pub e23: f64,
+    pub e24: f64,
+    pub e34: f64,
+    pub e1234: f64,
+}
+
+impl DRotor {
+    pub const IDENTITY: Self = Self {
+        s: 1.0,
+        e12: 0.0,
+        e13: 0.0,
+        e14: 0.0,
+        e23: 0.0,
+        e24: 0.0,
+        e34: 0.0,
+        e1234: 0.0,
+    };
+
+    #[must_use]
+    pub fn rotation_xy(angle: f64) -> Self {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Self {
+            s: cos,
+            e12: sin,
+            ..Self::IDENTITY
+        }
+    }
+
+    #[must_use]
+    pub fn rotation_xz(angle: f64) -> Self {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Self {
+            s: cos,
+            e13: -sin,
+            ..Self::IDENTITY
+        }
+    }
+
+    #[must_use]
+    pub fn rotation_xw(angle: f64) -> Self {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Self {
+            s: cos,
+            e14: sin,
+            ..Self::IDENTITY
+        }
+    }
+
+    #[must_use]
+    pub fn rotation_yz(angle: f64) -> Self {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Self {
+            s: cos,
+            e23: sin,
+            ..Self::IDENTITY
+        }
+    }
+
+    #[must_use]
+    pub fn rotation_yw(angle: f64) -> Self {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Self {
+            s: cos,
+            e24: -sin,
+            ..Self::IDENTITY
+        }
+    }
+
+    #[must_use]
+    pub fn rotation_zw(angle: f64) -> Self {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        Self {
+            s: cos,
+            e34: sin,
+            ..Self::IDENTITY
+        }
+    }
+
+    #[must_use]
+    pub const fn magnitude_squared(self) -> f64 {
+        self.then(self.inverse()).s
+    }
+
+    #[must_use]
+    pub fn magnitude(self) -> f64 {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Projects back onto the valid rotations, the same way as [`Rotor::normalized`]
+    #[must_use]
+    pub fn normalized(self) -> Self {
+        let (left, right) = self.to_quaternion_pair();
+        Self::from_quaternion_pair(left.normalize(), right.normalize())
+    }
+
+    #[must_use]
+    pub const fn inverse(self) -> Self {
+        let Self {
+            s,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            e1234,
+        } = self;
+        Self {
+            s,
+            e12: -e12,
+            e13: -e13,
+            e14: -e14,
+            e23: -e23,
+            e24: -e24,
+            e34: -e34,
+            e1234,
+        }
+    }
+
+    #[must_use]
+    pub(crate) const fn negated(self) -> Self {
+        let Self {
+            s,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            e1234,
+        } = self;
+        Self {
+            s: -s,
+            e12: -e12,
+            e13: -e13,
+            e14: -e14,
+            e23: -e23,
+            e24: -e24,
+            e34: -e34,
+            e1234: -e1234,
+        }
+    }
+
+    /// Whether every component is within `max_abs_diff` of the matching component of `other`
+    #[must_use]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f64) -> bool {
+        let a: [f64; 8] = bytemuck::cast(self);
+        let b: [f64; 8] = bytemuck::cast(other);
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() <= max_abs_diff)
+    }
+
+    /// Like [`Self::abs_diff_eq`] but also true when `other` is close to `-self`, which is the same rotation
+    #[must_use]
+    pub fn approx_eq(self, other: Self, max_abs_diff: f64) -> bool {
+        self.abs_diff_eq(other, max_abs_diff) || self.abs_diff_eq(-other, max_abs_diff)
+    }
+
+    #[must_use]
+    pub const fn then(self, other: Self) -> Self {
+        let Self {
+            s: a1,
+            e12: b1,
+            e13: c1,
+            e14: d1,
+            e23: e1,
+            e24: f1,
+            e34: g1,
+            e1234: h1,
+        } = self;
+        let Self {
+            s: a2,
+            e12: b2,
+            e13: c2,
+            e14: d2,
+            e23: e2,
+            e24: f2,
+            e34: g2,
+            e1234: h2,
+        } = other;
+        Self {
+            s: -b1 * b2 + -c1 * c2 + -d1 * d2 + -e1 * e2 + -f1 * f2 + -g1 * g2 + a1 * a2 + h1 * h2,
+            e12: -c1 * e2 + -d1 * f2 + -g1 * h2 + -g2 * h1 + a1 * b2 + a2 * b1 + c2 * e1 + d2 * f1,
+            e13: -b2 * e1 + -d1 * g2 + a1 * c2 + a2 * c1 + b1 * e2 + d2 * g1 + f1 * h2 + f2 * h1,
+            e14: -b2 * f1 + -c2 * g1 + -e1 * h2 + -e2 * h1 + a1 * d2 + a2 * d1 + b1 * f2 + c1 * g2,
+            e23: -b1 * c2 + -d1 * h2 + -d2 * h1 + -f1 * g2 + a1 * e2 + a2 * e1 + b2 * c1 + f2 * g1,
+            e24: -b1 * d2 + -e2 * g1 + a1 * f2 + a2 * f1 + b2 * d1 + c1 * h2 + c2 * h1 + e1 * g2,
+            e34: -b1 * h2 + -b2 * h1 + -c1 * d2 + -e1 * f2 + a1 * g2 + a2 * g1 + c2 * d1 + e2 * f1,
+            e1234: -c1 * f2 + -c2 * f1 + a1 * h2 + a2 * h1 + b1 * g2 + b2 * g1 + d1 * e2 + d2 * e1,
+        }
+    }
+
+    #[must_use]
+    pub const fn rotate(self, direction: DVec4) -> DVec4 {
+        let Self {
+            s: a,
+            e12: b,
+            e13: c,
+            e14: d,
+            e23: e,
+            e24: f,
+            e34: g,
+            e1234: h,
+        } = self;
+        let DVec4 {
+            x: p3,
+            y: p2,
+            z: p1,
+            w: p0,
+        } = direction;
+        let ap2 = a * p2;
+        let bp3 = b * p3;
+        let ep1 = e * p1;
+        let fp0 = f * p0;
+        let ap3 = a * p3;
+        let bp2 = b * p2;
+        let cp1 = c * p1;
+        let dp0 = d * p0;
+        let ap1 = a * p1;
+        let gp0 = g * p0;
+        let cp3 = c * p3;
+        let ep2 = e * p2;
+        let ap0 = a * p0;
+        let gp1 = g * p1;
+        let dp3 = d * p3;
+        let fp2 = f * p2;
+        let s0 = ep1 - ap2 - bp3 - fp0;
+        let s1 = ap3 + cp1 - bp2 - dp0;
+        let s2 = ap1 + ep2 - gp0 - cp3;
+        let s3 = fp2 - ap0 - gp1 - dp3;
+        let [w, z, y, x] = [
+            p0 + 2.0 * (h * (b * p1 + c * p2 + e * p3 - h * p0) + f * s0 + d * s1 + g * s2),
+            p1 + 2.0 * (h * (d * p2 + f * p3 - h * p1 - b * p0) + g * s3 - e * s0 - c * s1),
+            p2 + 2.0 * (h * (g * p3 - h * p2 - c * p0 - d * p1) + b * s1 - f * s3 - e * s2),
+            p3 + 2.0 * (d * s3 + c * s2 + b * s0 - h * (g * p2 + h * p3 + e * p0 + f * p1)),
+        ];
+        DVec4 { x, y, z, w }
+    }
+
+    /// Converts to the left and right unit quaternions of SO(4), which rotate a direction as
+    /// `left * (x + yi + zj + wk) * right.conjugate()`, the direction being `DQuat::from_xyzw(y, z, w, x)`
+    #[must_use]
+    pub const fn to_quaternion_pair(self) -> (DQuat, DQuat) {
+        let Self {
+            s,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            e1234,
+        } = self;
+        (
+            DQuat::from_xyzw(e12 + e34, e24 - e13, e14 + e23, s - e1234),
+            DQuat::from_xyzw(e34 - e12, e13 + e24, e23 - e14, s + e1234),
+        )
+    }
+
+    #[must_use]
+    pub fn from_quaternion_pair(left: DQuat, right: DQuat) -> Self {
+        Self {
+            s: (left.w + right.w) * 0.5,
+            e12: (left.x - right.x) * 0.5,
+            e13: (right.y - left.y) * 0.5,
+            e14: (left.z - right.z) * 0.5,
+            e23: (left.z + right.z) * 0.5,
+            e24: (left.y + right.y) * 0.5,
+            e34: (left.x + right.x) * 0.5,
+            e1234: (right.w - left.w) * 0.5,
+        }
+    }
+
+    /// Rounds each component to the nearest `f32`
+    #[must_use]
+    pub const fn as_rotor(self) -> Rotor {
+        Rotor {
+            s: self.s as f32,
+            e12: self.e12 as f32,
+            e13: self.e13 as f32,
+            e14: self.e14 as f32,
+            e23: self.e23 as f32,
+            e24: self.e24 as f32,
+            e34: self.e34 as f32,
+            e1234: self.e1234 as f32,
+        }
+    }
+}
+
+impl Default for DRotor {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// `a * b` is `a.then(b)`, so `(a * b) * direction == a * (b * direction)`
+impl Mul for DRotor {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.then(other)
+    }
+}
+
+impl MulAssign for DRotor {
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.then(other);
+    }
+}
+
+impl Mul<DVec4> for DRotor {
+    type Output = DVec4;
+
+    fn mul(self, direction: DVec4) -> DVec4 {
+        self.rotate(direction)
+    }
+}
+
+/// Rotates the point around the origin
+impl Mul<DPoint4> for DRotor {
+    type Output = DPoint4;
+
+    fn mul(self, point: DPoint4) -> DPoint4 {
+        DPoint4::from_vector(self.rotate(point.to_vector()))
+    }
+}
+
+/// The negated rotor is the same rotation
+impl Neg for DRotor {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.negated()
+    }
+}
+
+impl From<Rotor> for DRotor {
+    fn from(rotor: Rotor) -> Self {
+        rotor.as_drotor()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Transform, Vec4,
+        test_fixtures::{arbitrary_transforms, rotors},
+    };
+
+    // the f32 version rounds after every product, so it only agrees to about this much
+    const TOLERANCE: f32 = 1e-4;
+
+    #[test]
+    fn matches_f32() {
+        let arbitrary = arbitrary_transforms(16)
+            .into_iter()
+            .map(Transform::rotor_part);
+        let rotors = rotors().into_iter().chain(arbitrary).collect::<Vec<_>>();
+        for (&a, &b) in rotors.iter().zip(rotors.iter().rev()) {
+            let (da, db) = (DRotor::from(a), DRotor::from(b));
+            assert!(da.then(db).as_rotor().abs_diff_eq(a.then(b), TOLERANCE));
+            assert!(da.inverse().as_rotor().abs_diff_eq(a.inverse(), TOLERANCE));
+            assert!(
+                da.normalized()
+                    .as_rotor()
+                    .abs_diff_eq(a.normalized(), TOLERANCE)
+            );
+
+            let (left, right) = a.to_quaternion_pair();
+            let (d_left, d_right) = da.to_quaternion_pair();
+            assert!(d_left.as_quat().abs_diff_eq(left, TOLERANCE));
+            assert!(d_right.as_quat().abs_diff_eq(right, TOLERANCE));
+
+            let direction = Vec4::new(0.3, -1.2, 2.0, 0.7);
+            let rotated = da.rotate(direction.as_dvec4()).as_vec4();
+            assert!(rotated.abs_diff_eq(a.rotate(direction), TOLERANCE), "{a:?}");
+        }
+    }
+}
@@ -0,0 +1,651 @@
+use crate::{DPoint4, DRotor, DVec4, Transform};
+use bevy::reflect::{Reflect, prelude::ReflectDefault};
+use bytemuck::{Pod, Zeroable};
+use std::ops::{Mul, MulAssign, Neg};
+
+/// A [`Transform`] with `f64` precision, for composing transforms far from the origin
+/// before rounding the result for rendering
+#[derive(Reflect, Debug, Clone, Copy, Zeroable, Pod)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[repr(C)]
+pub struct DTransform {
+    pub s: f64,
+    pub e01: f64,
+    pub e02: f64,
+    pub e03: f64,
+    pub e04: f64,
+    pub e12: f64,
+    pub e13: f64,
+    pub e14: f64,
+    pub e23: f64,
+    pub e24: f64,
+    pub e34: f64,
+    pub e0123: f64,
+    pub e0124: f64,
+    pub e0134: f64,
+    pub e0234: f64,
+    pub e1234: f64,
+}
+
+impl DTransform {
+    pub const IDENTITY: Self = Self {
+        s: 1.0,
+        e01: 0.0,
+        e02: 0.0,
+        e03: 0.0,
+        e04: 0.0,
+        e12: 0.0,
+        e13: 0.0,
+        e14: 0.0,
+        e23: 0.0,
+        e24: 0.0,
+        e34: 0.0,
+        e0123: 0.0,
+        e0124: 0.0,
+        e0134: 0.0,
+        e0234: 0.0,
+        e1234: 0.0,
+    };
+
+    #[must_use]
+    pub const fn translation(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self {
+            e01: x * 0.5,
+            e02: y * -0.5,
+            e03: z * 0.5,
+            e04: w * -0.5,
+            ..Self::IDENTITY
+        }
+    }
+
+    #[must_use]
+    pub const fn from_rotor(rotor: DRotor) -> Self {
+        let DRotor {
+            s,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            e1234,
+        } = rotor;
+        Self {
+            s,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            e1234,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Rotates by `rotor` and then translates by `translation`
+    #[must_use]
+    pub const fn from_translation_rotor(translation: DVec4, rotor: DRotor) -> Self {
+        let DVec4 { x, y, z, w } = translation;
+        Self::translation(x, y, z, w).then(Self::from_rotor(rotor))
+    }
+
+    #[must_use]
+    pub fn rotation_xy(angle: f64) -> Self {
+        Self::from(DRotor::rotation_xy(angle))
+    }
+
+    #[must_use]
+    pub fn rotation_xz(angle: f64) -> Self {
+        Self::from(DRotor::rotation_xz(angle))
+    }
+
+    #[must_use]
+    pub fn rotation_xw(angle: f64) -> Self {
+        Self::from(DRotor::rotation_xw(angle))
+    }
+
+    #[must_use]
+    pub fn rotation_yz(angle: f64) -> Self {
+        Self::from(DRotor::rotation_yz(angle))
+    }
+
+    #[must_use]
+    pub fn rotation_yw(angle: f64) -> Self {
+        Self::from(DRotor::rotation_yw(angle))
+    }
+
+    #[must_use]
+    pub fn rotation_zw(angle: f64) -> Self {
+        Self::from(DRotor::rotation_zw(angle))
+    }
+
+    #[must_use]
+    pub const fn magnitude_squared(self) -> f64 {
+        self.then(self.inverse()).s
+    }
+
+    #[must_use]
+    pub fn magnitude(self) -> f64 {
+        self.magnitude_squared().sqrt()
+    }
+
+    /// Projects back onto the rigid motions, the same way as [`Transform::normalised`]
+    #[must_use]
+    pub fn normalised(self) -> Self {
+        let (translation, rotor) = self.to_translation_rotor();
+        Self::from_translation_rotor(translation, rotor.normalized())
+    }
+
+    #[must_use]
+    pub const fn inverse(self) -> Self {
+        let Self {
+            s,
+            e01,
+            e02,
+            e03,
+            e04,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            e0123,
+            e0124,
+            e0134,
+            e0234,
+            e1234,
+        } = self;
+        Self {
+            s,
+            e01: -e01,
+            e02: -e02,
+            e03: -e03,
+            e04: -e04,
+            e12: -e12,
+            e13: -e13,
+            e14: -e14,
+            e23: -e23,
+            e24: -e24,
+            e34: -e34,
+            e0123,
+            e0124,
+            e0134,
+            e0234,
+            e1234,
+        }
+    }
+
+    #[must_use]
+    pub(crate) const fn negated(self) -> Self {
+        let Self {
+            s,
+            e01,
+            e02,
+            e03,
+            e04,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            e0123,
+            e0124,
+            e0134,
+            e0234,
+            e1234,
+        } = self;
+        Self {
+            s: -s,
+            e01: -e01,
+            e02: -e02,
+            e03: -e03,
+            e04: -e04,
+            e12: -e12,
+            e13: -e13,
+            e14: -e14,
+            e23: -e23,
+            e24: -e24,
+            e34: -e34,
+            e0123: -e0123,
+            e0124: -e0124,
+            e0134: -e0134,
+            e0234: -e0234,
+            e1234: -e1234,
+        }
+    }
+
+    /// Whether every component is within `max_abs_diff` of the matching component of `other`
+    #[must_use]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f64) -> bool {
+        let a: [f64; 16] = bytemuck::cast(self);
+        let b: [f64; 16] = bytemuck::cast(other);
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() <= max_abs_diff)
+    }
+
+    /// Like [`Self::abs_diff_eq`] but also true when `other` is close to `-self`, which is the same transform
+    #[must_use]
+    pub fn approx_eq(self, other: Self, max_abs_diff: f64) -> bool {
+        self.abs_diff_eq(other, max_abs_diff) || self.abs_diff_eq(-other, max_abs_diff)
+    }
+
+    #[must_use]
+    pub const fn then(self, other: Self) -> Self {
+        let Self {
+            s: a1,
+            e01: b1,
+            e02: c1,
+            e03: d1,
+            e04: f1,
+            e12: g1,
+            e13: h1,
+            e14: i1,
+            e23: j1,
+            e24: k1,
+            e34: l1,
+            e0123: m1,
+            e0124: n1,
+            e0134: o1,
+            e0234: p1,
+            e1234: q1,
+        } = self;
+        let Self {
+            s: a2,
+            e01: b2,
+            e02: c2,
+            e03: d2,
+            e04: f2,
+            e12: g2,
+            e13: h2,
+            e14: i2,
+            e23: j2,
+            e24: k2,
+            e34: l2,
+            e0123: m2,
+            e0124: n2,
+            e0134: o2,
+            e0234: p2,
+            e1234: q2,
+        } = other;
+        Self {
+            s: -g1 * g2 + -h1 * h2 + -i1 * i2 + -j1 * j2 + -k1 * k2 + -l1 * l2 + a1 * a2 + q1 * q2,
+            e01: -c1 * g2
+                + -d1 * h2
+                + -f1 * i2
+                + -j1 * m2
+                + -j2 * m1
+                + -k1 * n2
+                + -k2 * n1
+                + -l1 * o2
+                + -l2 * o1
+                + -p2 * q1
+                + a1 * b2
+                + a2 * b1
+                + c2 * g1
+                + d2 * h1
+                + f2 * i1
+                + p1 * q2,
+            e02: -b2 * g1
+                + -d1 * j2
+                + -f1 * k2
+                + -l1 * p2
+                + -l2 * p1
+                + -o1 * q2
+                + a1 * c2
+                + a2 * c1
+                + b1 * g2
+                + d2 * j1
+                + f2 * k1
+                + h1 * m2
+                + h2 * m1
+                + i1 * n2
+                + i2 * n1
+                + o2 * q1,
+            e03: -b2 * h1
+                + -c2 * j1
+                + -f1 * l2
+                + -g1 * m2
+                + -g2 * m1
+                + -n2 * q1
+                + a1 * d2
+                + a2 * d1
+                + b1 * h2
+                + c1 * j2
+                + f2 * l1
+                + i1 * o2
+                + i2 * o1
+                + k1 * p2
+                + k2 * p1
+                + n1 * q2,
+            e04: -b2 * i1
+                + -c2 * k1
+                + -d2 * l1
+                + -g1 * n2
+                + -g2 * n1
+                + -h1 * o2
+                + -h2 * o1
+                + -j1 * p2
+                + -j2 * p1
+                + -m1 * q2
+                + a1 * f2
+                + a2 * f1
+                + b1 * i2
+                + c1 * k2
+                + d1 * l2
+                + m2 * q1,
+            e12: -h1 * j2 + -i1 * k2 + -l1 * q2 + -l2 * q1 + a1 * g2 + a2 * g1 + h2 * j1 + i2 * k1,
+            e13: -g2 * j1 + -i1 * l2 + a1 * h2 + a2 * h1 + g1 * j2 + i2 * l1 + k1 * q2 + k2 * q1,
+            e14: -g2 * k1 + -h2 * l1 + -j1 * q2 + -j2 * q1 + a1 * i2 + a2 * i1 + g1 * k2 + h1 * l2,
+            e23: -g1 * h2 + -i1 * q2 + -i2 * q1 + -k1 * l2 + a1 * j2 + a2 * j1 + g2 * h1 + k2 * l1,
+            e24: -g1 * i2 + -j2 * l1 + a1 * k2 + a2 * k1 + g2 * i1 + h1 * q2 + h2 * q1 + j1 * l2,
+            e34: -g1 * q2 + -g2 * q1 + -h1 * i2 + -j1 * k2 + a1 * l2 + a2 * l1 + h2 * i1 + j2 * k1,
+            e0123: -c1 * h2
+                + -c2 * h1
+                + -f1 * q2
+                + -i2 * p1
+                + -k1 * o2
+                + -l2 * n1
+                + a1 * m2
+                + a2 * m1
+                + b1 * j2
+                + b2 * j1
+                + d1 * g2
+                + d2 * g1
+                + f2 * q1
+                + i1 * p2
+                + k2 * o1
+                + l1 * n2,
+            e0124: -c1 * i2
+                + -c2 * i1
+                + -d2 * q1
+                + -h1 * p2
+                + -j2 * o1
+                + -l1 * m2
+                + a1 * n2
+                + a2 * n1
+                + b1 * k2
+                + b2 * k1
+                + d1 * q2
+                + f1 * g2
+                + f2 * g1
+                + h2 * p1
+                + j1 * o2
+                + l2 * m1,
+            e0134: -c1 * q2
+                + -d1 * i2
+                + -d2 * i1
+                + -g2 * p1
+                + -j1 * n2
+                + -k2 * m1
+                + a1 * o2
+                + a2 * o1
+                + b1 * l2
+                + b2 * l1
+                + c2 * q1
+                + f1 * h2
+                + f2 * h1
+                + g1 * p2
+                + j2 * n1
+                + k1 * m2,
+            e0234: -b2 * q1
+                + -d1 * k2
+                + -d2 * k1
+                + -g1 * o2
+                + -h2 * n1
+                + -i1 * m2
+                + a1 * p2
+                + a2 * p1
+                + b1 * q2
+                + c1 * l2
+                + c2 * l1
+                + f1 * j2
+                + f2 * j1
+                + g2 * o1
+                + h1 * n2
+                + i2 * m1,
+            e1234: -h1 * k2 + -h2 * k1 + a1 * q2 + a2 * q1 + g1 * l2 + g2 * l1 + i1 * j2 + i2 * j1,
+        }
+    }
+
+    #[must_use]
+    pub const fn transform(self, point: DPoint4) -> DPoint4 {
+        let Self {
+            s: a,
+            e01: b,
+            e02: c,
+            e03: d,
+            e04: f,
+            e12: g,
+            e13: h,
+            e14: i,
+            e23: j,
+            e24: k,
+            e34: l,
+            e0123: m,
+            e0124: n,
+            e0134: o,
+            e0234: p,
+            e1234: q,
+        } = self;
+        let DPoint4 {
+            x: p3,
+            y: p2,
+            z: p1,
+            w: p0,
+        } = point;
+        let ap2 = a * p2;
+        let gp3 = g * p3;
+        let jp1 = j * p1;
+        let kp0 = k * p0;
+        let ap3 = a * p3;
+        let gp2 = g * p2;
+        let hp1 = h * p1;
+        let ip0 = i * p0;
+        let ap1 = a * p1;
+        let lp0 = l * p0;
+        let hp3 = h * p3;
+        let jp2 = j * p2;
+        let ap0 = a * p0;
+        let lp1 = l * p1;
+        let ip3 = i * p3;
+        let kp2 = k * p2;
+        let s0 = c + jp1 - ap2 - gp3 - kp0;
+        let s1 = ap3 + b + hp1 - gp2 - ip0;
+        let s2 = ap1 + d + jp2 - lp0 - hp3;
+        let s3 = f + kp2 - ap0 - lp1 - ip3;
+        let [w, z, y, x] = [
+            p0 + 2.0
+                * (q * (m + g * p1 + h * p2 + j * p3 - q * p0) + k * s0 + i * s1 + l * s2
+                    - a * f
+                    - n * g
+                    - o * h
+                    - p * j),
+            p1 + 2.0
+                * (a * d + m * g + q * (n + i * p2 + k * p3 - q * p1 - g * p0) + l * s3
+                    - o * i
+                    - p * k
+                    - j * s0
+                    - h * s1),
+            p2 + 2.0
+                * (m * h + n * i + q * (l * p3 + o - q * p2 - h * p0 - i * p1) + g * s1
+                    - a * c
+                    - l * p
+                    - k * s3
+                    - j * s2),
+            p3 + 2.0
+                * (a * b
+                    + l * o
+                    + m * j
+                    + n * k
+                    + q * (p - l * p2 - q * p3 - j * p0 - k * p1)
+                    + i * s3
+                    + h * s2
+                    + g * s0),
+        ];
+        DPoint4 { x, y, z, w }
+    }
+
+    #[must_use]
+    pub const fn rotor_part(self) -> DRotor {
+        let Self {
+            s,
+            e01: _,
+            e02: _,
+            e03: _,
+            e04: _,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            e0123: _,
+            e0124: _,
+            e0134: _,
+            e0234: _,
+            e1234,
+        } = self;
+        DRotor {
+            s,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            e1234,
+        }
+    }
+
+    #[must_use]
+    pub const fn translation_part(self) -> DVec4 {
+        // removing the rotation leaves a pure translator,
+        // dividing by its scalar part keeps this correct for transforms that arent normalised
+        let translator = self.then(Self::from_rotor(self.rotor_part().inverse()));
+        let scale = 2.0 / translator.s;
+        DVec4 {
+            x: translator.e01 * scale,
+            y: translator.e02 * -scale,
+            z: translator.e03 * scale,
+            w: translator.e04 * -scale,
+        }
+    }
+
+    #[must_use]
+    pub const fn to_translation_rotor(self) -> (DVec4, DRotor) {
+        (self.translation_part(), self.rotor_part())
+    }
+
+    #[must_use]
+    pub const fn with_translation(self, translation: DVec4) -> Self {
+        Self::from_translation_rotor(translation, self.rotor_part())
+    }
+
+    #[must_use]
+    pub const fn with_rotor(self, rotor: DRotor) -> Self {
+        Self::from_translation_rotor(self.translation_part(), rotor)
+    }
+
+    /// Rounds each component to the nearest `f32`
+    #[must_use]
+    pub fn as_transform(self) -> Transform {
+        bytemuck::cast(bytemuck::cast::<_, [f64; 16]>(self).map(|component| component as f32))
+    }
+}
+
+impl Default for DTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<DRotor> for DTransform {
+    fn from(rotor: DRotor) -> Self {
+        Self::from_rotor(rotor)
+    }
+}
+
+/// `a * b` is `a.then(b)`, so `(a * b) * point == a * (b * point)`
+impl Mul for DTransform {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.then(other)
+    }
+}
+
+impl MulAssign for DTransform {
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.then(other);
+    }
+}
+
+/// Directions are only rotated, the translation doesnt affect them
+impl Mul<DVec4> for DTransform {
+    type Output = DVec4;
+
+    fn mul(self, direction: DVec4) -> DVec4 {
+        self.rotor_part().rotate(direction)
+    }
+}
+
+impl Mul<DPoint4> for DTransform {
+    type Output = DPoint4;
+
+    fn mul(self, point: DPoint4) -> DPoint4 {
+        self.transform(point)
+    }
+}
+
+/// The negated transform moves every point the same way
+impl Neg for DTransform {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.negated()
+    }
+}
+
+impl From<Transform> for DTransform {
+    fn from(transform: Transform) -> Self {
+        transform.as_dtransform()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Point4, test_fixtures::arbitrary_transforms};
+
+    // the f32 version rounds after every product, so it only agrees to about this much
+    const TOLERANCE: f32 = 1e-4;
+
+    #[test]
+    fn matches_f32() {
+        let transforms = arbitrary_transforms(32);
+        for (&a, &b) in transforms.iter().zip(transforms.iter().rev()) {
+            let (da, db) = (DTransform::from(a), DTransform::from(b));
+            assert!(da.then(db).as_transform().abs_diff_eq(a.then(b), TOLERANCE));
+            assert!(
+                da.inverse()
+                    .as_transform()
+                    .abs_diff_eq(a.inverse(), TOLERANCE)
+            );
+            assert!(
+                da.normalised()
+                    .as_transform()
+                    .abs_diff_eq(a.normalised(), TOLERANCE)
+            );
+
+            let (a, da) = (a.normalised(), da.normalised());
+            for point in [Point4::ORIGIN, Point4::new(1.0, 2.0, -3.0, 0.5)] {
+                let expected = a.transform(point);
+                let point = da.transform(point.as_dpoint4()).as_point4();
+                assert!(point.abs_diff_eq(expected, TOLERANCE), "{a:?}");
+            }
+        }
+    }
+}
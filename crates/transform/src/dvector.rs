@@ -0,0 +1,225 @@
+use crate::Vec4;
+use bevy::reflect::{Reflect, prelude::ReflectDefault};
+use bytemuck::{Pod, Zeroable};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A direction or displacement in 4D space with `f64` precision
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Zeroable, Pod)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[repr(C)]
+pub struct DVec4 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+impl DVec4 {
+    pub const ZERO: Self = Self::splat(0.0);
+    pub const ONE: Self = Self::splat(1.0);
+    pub const X: Self = Self::new(1.0, 0.0, 0.0, 0.0);
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0, 0.0);
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0, 0.0);
+    pub const W: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+    pub const AXES: [Self; 4] = [Self::X, Self::Y, Self::Z, Self::W];
+
+    #[must_use]
+    pub const fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    #[must_use]
+    pub const fn splat(value: f64) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    #[must_use]
+    pub const fn dot(self, other: Self) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    #[must_use]
+    pub const fn length_squared(self) -> f64 {
+        self.dot(self)
+    }
+
+    #[must_use]
+    pub fn length(self) -> f64 {
+        self.length_squared().sqrt()
+    }
+
+    #[must_use]
+    pub fn normalised(self) -> Self {
+        self / self.length()
+    }
+
+    /// Returns [`None`] when the vector is too close to zero to have a direction
+    #[must_use]
+    pub fn try_normalised(self) -> Option<Self> {
+        let inverse_length = self.length().recip();
+        (inverse_length.is_finite() && inverse_length > 0.0).then(|| self * inverse_length)
+    }
+
+    #[must_use]
+    pub fn normalised_or_zero(self) -> Self {
+        self.try_normalised().unwrap_or(Self::ZERO)
+    }
+
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        self + (other - self) * t
+    }
+
+    #[must_use]
+    pub const fn to_array(self) -> [f64; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Whether every component is within `max_abs_diff` of the matching component of `other`
+    #[must_use]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f64) -> bool {
+        let (a, b) = (self.to_array(), other.to_array());
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() <= max_abs_diff)
+    }
+
+    /// Rounds each component to the nearest `f32`
+    #[must_use]
+    pub const fn as_vec4(self) -> Vec4 {
+        Vec4::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+}
+
+impl Add for DVec4 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            self.x + other.x,
+            self.y + other.y,
+            self.z + other.z,
+            self.w + other.w,
+        )
+    }
+}
+
+impl AddAssign for DVec4 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for DVec4 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(
+            self.x - other.x,
+            self.y - other.y,
+            self.z - other.z,
+            self.w - other.w,
+        )
+    }
+}
+
+impl SubAssign for DVec4 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<f64> for DVec4 {
+    type Output = Self;
+
+    fn mul(self, scale: f64) -> Self {
+        Self::new(
+            self.x * scale,
+            self.y * scale,
+            self.z * scale,
+            self.w * scale,
+        )
+    }
+}
+
+impl Mul<DVec4> for f64 {
+    type Output = DVec4;
+
+    fn mul(self, vector: DVec4) -> DVec4 {
+        vector * self
+    }
+}
+
+impl MulAssign<f64> for DVec4 {
+    fn mul_assign(&mut self, scale: f64) {
+        *self = *self * scale;
+    }
+}
+
+impl Div<f64> for DVec4 {
+    type Output = Self;
+
+    fn div(self, scale: f64) -> Self {
+        Self::new(
+            self.x / scale,
+            self.y / scale,
+            self.z / scale,
+            self.w / scale,
+        )
+    }
+}
+
+impl DivAssign<f64> for DVec4 {
+    fn div_assign(&mut self, scale: f64) {
+        *self = *self / scale;
+    }
+}
+
+impl Neg for DVec4 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl From<(f64, f64, f64, f64)> for DVec4 {
+    fn from((x, y, z, w): (f64, f64, f64, f64)) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+impl From<DVec4> for (f64, f64, f64, f64) {
+    fn from(DVec4 { x, y, z, w }: DVec4) -> Self {
+        (x, y, z, w)
+    }
+}
+
+impl From<[f64; 4]> for DVec4 {
+    fn from([x, y, z, w]: [f64; 4]) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+impl From<DVec4> for [f64; 4] {
+    fn from(vector: DVec4) -> Self {
+        vector.to_array()
+    }
+}
+
+impl From<bevy::math::DVec4> for DVec4 {
+    fn from(vector: bevy::math::DVec4) -> Self {
+        Self::from(vector.to_array())
+    }
+}
+
+impl From<DVec4> for bevy::math::DVec4 {
+    fn from(vector: DVec4) -> Self {
+        Self::from_array(vector.to_array())
+    }
+}
+
+impl From<Vec4> for DVec4 {
+    fn from(vector: Vec4) -> Self {
+        vector.as_dvec4()
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{GlobalGridCell, GlobalScale, Point4, Reflection, Scale, Transform, Vec4};
+use bevy::{
+    ecs::component::Component,
+    reflect::{Reflect, prelude::ReflectDefault},
+};
+use std::ops::Mul;
+
+/// The combined transform of an entity and all its parents,
+/// mirrored when an odd number of them have a [`Reflection`]
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[require(GlobalScale, GlobalGridCell, TransformTreeChanged)]
+pub enum GlobalTransform {
+    Rigid(Transform),
+    Mirrored(Reflection),
+}
+
+impl GlobalTransform {
+    pub const IDENTITY: Self = Self::Rigid(Transform::IDENTITY);
+
+    #[must_use]
+    pub const fn is_mirrored(self) -> bool {
+        matches!(self, Self::Mirrored(_))
+    }
+
+    /// The transform when it isnt mirrored
+    #[must_use]
+    pub const fn as_rigid(self) -> Option<Transform> {
+        match self {
+            Self::Rigid(transform) => Some(transform),
+            Self::Mirrored(_) => None,
+        }
+    }
+
+    /// The rigid motion part, for a mirrored transform this is what is left
+    /// after undoing a reflection in the hyperplane `x = 0`, see [`Reflection::to_transform`]
+    #[must_use]
+    pub const fn motor(self) -> Transform {
+        match self {
+            Self::Rigid(transform) => transform,
+            Self::Mirrored(reflection) => reflection.to_transform(),
+        }
+    }
+
+    #[must_use]
+    pub fn normalised(self) -> Self {
+        match self {
+            Self::Rigid(transform) => Self::Rigid(transform.normalised()),
+            Self::Mirrored(reflection) => Self::Mirrored(reflection.normalised()),
+        }
+    }
+
+    #[must_use]
+    pub const fn inverse(self) -> Self {
+        match self {
+            Self::Rigid(transform) => Self::Rigid(transform.inverse()),
+            Self::Mirrored(reflection) => Self::Mirrored(reflection.inverse()),
+        }
+    }
+
+    #[must_use]
+    pub fn then(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Rigid(a), Self::Rigid(b)) => Self::Rigid(a.then(b)),
+            (Self::Rigid(a), Self::Mirrored(b)) => {
+                Self::Mirrored(Reflection::from_transform(a.then(b.to_transform())))
+            }
+            (Self::Mirrored(a), Self::Rigid(b)) => Self::Mirrored(a.then(b)),
+            (Self::Mirrored(a), Self::Mirrored(b)) => Self::Rigid(a.then_reflection(b)),
+        }
+    }
+
+    /// The transform that gives the same result before `scale` as this one does after it,
+    /// so only the translation is scaled
+    #[must_use]
+    pub(crate) const fn with_scaled_translation(self, scale: Scale) -> Self {
+        const fn scaled(transform: Transform, scale: Scale) -> Transform {
+            transform.with_translation(scale.scale(transform.translation_part()))
+        }
+        if matches!(scale, Scale::Uniform(1.0)) {
+            return self;
+        }
+        match self {
+            Self::Rigid(transform) => Self::Rigid(scaled(transform, scale)),
+            Self::Mirrored(reflection) => Self::Mirrored(Reflection::from_transform(scaled(
+                reflection.to_transform(),
+                scale,
+            ))),
+        }
+    }
+
+    #[must_use]
+    pub fn transform(self, point: Point4) -> Point4 {
+        match self {
+            Self::Rigid(transform) => transform.transform(point),
+            Self::Mirrored(reflection) => reflection.transform(point),
+        }
+    }
+
+    /// Transforms every point in place, see [`Transform::transform_points`]
+    pub fn transform_points(self, points: &mut [Point4]) {
+        match self {
+            Self::Rigid(transform) => transform.transform_points(points),
+            Self::Mirrored(reflection) => reflection.transform_points(points),
+        }
+    }
+
+    /// Directions are only rotated and mirrored, the translation doesnt affect them
+    #[must_use]
+    pub const fn transform_direction(self, direction: Vec4) -> Vec4 {
+        match self {
+            Self::Rigid(transform) => transform.rotor_part().rotate(direction),
+            Self::Mirrored(reflection) => reflection.transform_direction(direction),
+        }
+    }
+}
+
+/// Flagged as changed when the global transform of the entity or anything below it has to be recomputed,
+/// so subtrees where nothing changed can be skipped
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Default, Clone)]
+pub struct TransformTreeChanged;
+
+impl Default for GlobalTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Transform> for GlobalTransform {
+    fn from(transform: Transform) -> Self {
+        Self::Rigid(transform)
+    }
+}
+
+impl From<Reflection> for GlobalTransform {
+    fn from(reflection: Reflection) -> Self {
+        Self::Mirrored(reflection)
+    }
+}
+
+/// `a * b` is `a.then(b)`, so `(a * b) * point == a * (b * point)`
+impl Mul for GlobalTransform {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.then(other)
+    }
+}
+
+impl Mul<Point4> for GlobalTransform {
+    type Output = Point4;
+
+    fn mul(self, point: Point4) -> Point4 {
+        self.transform(point)
+    }
+}
+
+impl Mul<Vec4> for GlobalTransform {
+    type Output = Vec4;
+
+    fn mul(self, direction: Vec4) -> Vec4 {
+        self.transform_direction(direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TransformPlugin;
+    use bevy::{app::App, ecs::hierarchy::ChildOf};
+
+    const TOLERANCE: f32 = 1e-5;
+
+    #[test]
+    fn motor_of_mirrored() {
+        let transform =
+            Transform::translation(1.0, 2.0, 3.0, 4.0).then(Transform::rotation_yw(0.5));
+        let rigid = GlobalTransform::from(transform);
+        assert!(rigid.motor().abs_diff_eq(transform, TOLERANCE));
+        assert!(rigid.as_rigid().is_some());
+
+        let mirrored = GlobalTransform::from(Reflection::from_transform(transform));
+        assert!(mirrored.motor().abs_diff_eq(transform, TOLERANCE));
+        assert!(mirrored.as_rigid().is_none());
+    }
+
+    #[test]
+    fn reflected_parent_mirrors_child() {
+        let mut app = App::new();
+        app.add_plugins(TransformPlugin);
+        let parent = app
+            .world_mut()
+            .spawn((
+                Transform::translation(0.0, 1.0, 0.0, 0.0),
+                Reflection::MIRROR_X,
+            ))
+            .id();
+        let child = app
+            .world_mut()
+            .spawn((Transform::translation(2.0, 3.0, 0.0, 0.0), ChildOf(parent)))
+            .id();
+        app.update();
+
+        let global = *app.world().get::<GlobalTransform>(child).unwrap();
+        assert!(global.is_mirrored());
+        // mirrored in x = 0 in the space of the parent and then moved up by its translation
+        assert!((global * Point4::ORIGIN).abs_diff_eq(Point4::new(-2.0, 4.0, 0.0, 0.0), TOLERANCE));
+        assert!(
+            (global * Point4::new(1.0, 0.0, 1.0, 0.0))
+                .abs_diff_eq(Point4::new(-3.0, 4.0, 1.0, 0.0), TOLERANCE)
+        );
+        assert!((global * Vec4::X).abs_diff_eq(-Vec4::X, TOLERANCE));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{DVec4, GlobalTransform, Vec4};
+use bevy::{
+    ecs::{component::Component, reflect::ReflectResource, resource::Resource},
+    reflect::{Reflect, prelude::ReflectDefault},
+};
+use std::ops::{Add, Sub};
+
+/// The cell of the floating origin grid an entity without a parent lives in,
+/// its [`crate::Transform`] is relative to the centre of the cell and is moved into a neighbouring cell
+/// once it gets further than half of [`GridSettings::cell_size`] from it, children are always in the cell of their root
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[require(GlobalTransform)]
+pub struct GridCell {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+    pub w: i64,
+}
+
+impl GridCell {
+    pub const ZERO: Self = Self::new(0, 0, 0, 0);
+
+    #[must_use]
+    pub const fn new(x: i64, y: i64, z: i64, w: i64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// The position of the centre of this cell relative to the centre of `origin`
+    #[must_use]
+    pub const fn offset_from(self, origin: Self, cell_size: f32) -> DVec4 {
+        let cell_size = cell_size as f64;
+        DVec4::new(
+            (self.x - origin.x) as f64 * cell_size,
+            (self.y - origin.y) as f64 * cell_size,
+            (self.z - origin.z) as f64 * cell_size,
+            (self.w - origin.w) as f64 * cell_size,
+        )
+    }
+
+    /// The cells between the cell centre and `translation`, rounded to the nearest one
+    #[must_use]
+    pub fn containing(translation: Vec4, cell_size: f32) -> Self {
+        let Vec4 { x, y, z, w } = translation / cell_size;
+        Self::new(
+            x.round() as i64,
+            y.round() as i64,
+            z.round() as i64,
+            w.round() as i64,
+        )
+    }
+}
+
+impl Add for GridCell {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            self.x + other.x,
+            self.y + other.y,
+            self.z + other.z,
+            self.w + other.w,
+        )
+    }
+}
+
+impl Sub for GridCell {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(
+            self.x - other.x,
+            self.y - other.y,
+            self.z - other.z,
+            self.w - other.w,
+        )
+    }
+}
+
+/// The [`GridCell`] of the root of an entity, its [`GlobalTransform`] is relative to the centre of this cell
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+pub struct GlobalGridCell(pub GridCell);
+
+#[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Resource, Default, Clone)]
+pub struct GridSettings {
+    /// The width of a cell along every axis, the larger it is the less precise positions near its edges get
+    pub cell_size: f32,
+}
+
+impl Default for GridSettings {
+    fn default() -> Self {
+        Self { cell_size: 1000.0 }
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{
+    FixedStepTransforms, GlobalGridCell, GlobalScale, GlobalTransform, GridCell,
+    InterpolateTransform, Reflection, Scale, Transform,
+    interpolation::{fixed_step_transform, propagated_transform},
+    local_transform,
+};
+use bevy::ecs::{
+    entity::{Entity, EntityHashSet},
+    hierarchy::ChildOf,
+    query::Has,
+    system::{Query, SystemParam},
+};
+use std::{error::Error, fmt};
+
+/// Computes global transforms on demand by walking up [`ChildOf`],
+/// for systems that need them before [`crate::TransformPlugin`] has updated [`GlobalTransform`] for this frame,
+/// put it in a `ParamSet` together with any query that mutates [`Transform`]
+#[derive(SystemParam)]
+pub struct TransformHelper<'w, 's> {
+    transforms: Query<
+        'w,
+        's,
+        (
+            Option<&'static Transform>,
+            Option<&'static Reflection>,
+            Option<&'static Scale>,
+            Option<&'static GridCell>,
+            Option<&'static FixedStepTransforms>,
+            Has<InterpolateTransform>,
+            Option<&'static ChildOf>,
+        ),
+    >,
+}
+
+impl TransformHelper<'_, '_> {
+    pub fn compute_global_transform(
+        &self,
+        entity: Entity,
+    ) -> Result<GlobalTransform, ComputeGlobalTransformError> {
+        self.compute(entity).map(|(transform, _)| transform)
+    }
+
+    pub fn compute_global_scale(
+        &self,
+        entity: Entity,
+    ) -> Result<GlobalScale, ComputeGlobalTransformError> {
+        self.compute(entity).map(|(_, scale)| scale)
+    }
+
+    /// Both at once, the same as what propagation would give,
+    /// including using the interpolated pose of entities with [`InterpolateTransform`]
+    pub fn compute(
+        &self,
+        entity: Entity,
+    ) -> Result<(GlobalTransform, GlobalScale), ComputeGlobalTransformError> {
+        self.compute_from(entity, propagated_transform)
+    }
+
+    /// Like [`Self::compute`] but from the poses after the last fixed timestep instead of the interpolated ones
+    pub(crate) fn compute_fixed_step(
+        &self,
+        entity: Entity,
+    ) -> Result<(GlobalTransform, GlobalScale), ComputeGlobalTransformError> {
+        self.compute_from(entity, fixed_step_transform)
+    }
+
+    fn compute_from(
+        &self,
+        entity: Entity,
+        pose: for<'a> fn(
+            Option<&'a Transform>,
+            Option<&'a FixedStepTransforms>,
+            bool,
+        ) -> Option<&'a Transform>,
+    ) -> Result<(GlobalTransform, GlobalScale), ComputeGlobalTransformError> {
+        let mut computed_transform = GlobalTransform::IDENTITY;
+        let mut computed_scale = Scale::ONE;
+
+        let mut visited = EntityHashSet::default();
+        let mut next = entity;
+        loop {
+            let Ok((transform, reflection, scale, _, poses, interpolates, child_of)) =
+                self.transforms.get(next)
+            else {
+                return Err(if next == entity {
+                    ComputeGlobalTransformError::NoSuchEntity(entity)
+                } else {
+                    ComputeGlobalTransformError::MalformedHierarchy(next)
+                });
+            };
+
+            let transform = pose(transform, poses, interpolates);
+            let (transform, scale) = local_transform(transform, reflection, scale);
+            computed_transform = transform.then(computed_transform.with_scaled_translation(scale));
+            computed_scale = scale.then(computed_scale);
+
+            let Some(child_of) = child_of else {
+                break;
+            };
+            visited.insert(next);
+            next = child_of.0;
+            if visited.contains(&next) {
+                return Err(ComputeGlobalTransformError::MalformedHierarchy(next));
+            }
+        }
+
+        Ok((computed_transform.normalised(), GlobalScale(computed_scale)))
+    }
+
+    /// The cell of the root of the entity
+    pub fn compute_global_grid_cell(
+        &self,
+        entity: Entity,
+    ) -> Result<GlobalGridCell, ComputeGlobalTransformError> {
+        let mut visited = EntityHashSet::default();
+        let mut next = entity;
+        loop {
+            let Ok((_, _, _, grid_cell, _, _, child_of)) = self.transforms.get(next) else {
+                return Err(if next == entity {
+                    ComputeGlobalTransformError::NoSuchEntity(entity)
+                } else {
+                    ComputeGlobalTransformError::MalformedHierarchy(next)
+                });
+            };
+
+            let Some(child_of) = child_of else {
+                return Ok(GlobalGridCell(grid_cell.copied().unwrap_or_default()));
+            };
+            visited.insert(next);
+            next = child_of.0;
+            if visited.contains(&next) {
+                return Err(ComputeGlobalTransformError::MalformedHierarchy(next));
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComputeGlobalTransformError {
+    NoSuchEntity(Entity),
+    /// The entity has a parent that doesnt exist or is its own ancestor
+    MalformedHierarchy(Entity),
+}
+
+impl fmt::Display for ComputeGlobalTransformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSuchEntity(entity) => write!(f, "the entity {entity} doesnt exist"),
+            Self::MalformedHierarchy(entity) => {
+                write!(f, "the ancestor {entity} is missing or part of a cycle")
+            }
+        }
+    }
+}
+
+impl Error for ComputeGlobalTransformError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Point4, TransformPlugin, Vec4, Velocity4};
+    use bevy::{
+        app::App,
+        ecs::system::RunSystemOnce,
+        time::{TimePlugin, TimeUpdateStrategy},
+    };
+    use std::time::Duration;
+
+    fn compute(
+        app: &mut App,
+        entity: Entity,
+    ) -> Result<GlobalTransform, ComputeGlobalTransformError> {
+        app.world_mut()
+            .run_system_once(move |helper: TransformHelper| helper.compute_global_transform(entity))
+            .unwrap()
+    }
+
+    #[test]
+    fn matches_propagation_while_interpolating() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, TransformPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(7)));
+        let parent = app
+            .world_mut()
+            .spawn((
+                Velocity4(Vec4::new(1.0, 0.0, 0.0, 0.0)),
+                InterpolateTransform,
+            ))
+            .id();
+        let child = app
+            .world_mut()
+            .spawn((Transform::translation(0.0, 1.0, 0.0, 0.0), ChildOf(parent)))
+            .id();
+        for _ in 0..20 {
+            app.update();
+            let computed = compute(&mut app, child).unwrap() * Point4::ORIGIN;
+            let propagated = *app.world().get::<GlobalTransform>(child).unwrap() * Point4::ORIGIN;
+            assert!(
+                computed.abs_diff_eq(propagated, 1e-5),
+                "{computed:?} {propagated:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn cycles_are_malformed() {
+        let mut app = App::new();
+        let [a, b, c] = [(); 3].map(|()| app.world_mut().spawn(Transform::IDENTITY).id());
+        app.world_mut().entity_mut(a).insert(ChildOf(b));
+        app.world_mut().entity_mut(b).insert(ChildOf(c));
+        app.world_mut().entity_mut(c).insert(ChildOf(a));
+        assert!(matches!(
+            compute(&mut app, a),
+            Err(ComputeGlobalTransformError::MalformedHierarchy(_))
+        ));
+    }
+}
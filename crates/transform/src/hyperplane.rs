@@ -0,0 +1,326 @@
+use crate::{Join, Line, Plane, Point4, Transform, Vec4, point::hyperplane_line_meet};
+use bevy::reflect::{Reflect, prelude::ReflectDefault};
+use bytemuck::{Pod, Zeroable};
+use std::ops::Mul;
+
+/// A 3D flat in 4D space, the points where `normal.dot(point) == offset`, stored as a vector of 4D PGA
+#[derive(Reflect, Debug, Default, Clone, Copy, Zeroable, Pod)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[repr(C)]
+pub struct Hyperplane {
+    pub e0: f32,
+    pub e1: f32,
+    pub e2: f32,
+    pub e3: f32,
+    pub e4: f32,
+}
+
+impl Hyperplane {
+    #[must_use]
+    pub const fn new(normal: Vec4, offset: f32) -> Self {
+        Self {
+            e0: offset,
+            e1: normal.x,
+            e2: -normal.y,
+            e3: normal.z,
+            e4: -normal.w,
+        }
+    }
+
+    #[must_use]
+    pub const fn from_point_normal(point: Point4, normal: Vec4) -> Self {
+        Self::new(normal, normal.dot(point.to_vector()))
+    }
+
+    #[must_use]
+    pub const fn normal(self) -> Vec4 {
+        Vec4::new(self.e1, -self.e2, self.e3, -self.e4)
+    }
+
+    #[must_use]
+    pub const fn offset(self) -> f32 {
+        self.e0
+    }
+
+    /// Scales the hyperplane so its normal has a length of 1
+    #[must_use]
+    pub fn normalised(self) -> Self {
+        let inverse_length = self.normal().length().recip();
+        let Self { e0, e1, e2, e3, e4 } = self;
+        Self {
+            e0: e0 * inverse_length,
+            e1: e1 * inverse_length,
+            e2: e2 * inverse_length,
+            e3: e3 * inverse_length,
+            e4: e4 * inverse_length,
+        }
+    }
+
+    /// Positive on the side the normal points towards, NaN if the normal is zero
+    #[must_use]
+    pub fn signed_distance(self, point: Point4) -> f32 {
+        let normal = self.normal();
+        (normal.dot(point.to_vector()) - self.offset()) / normal.length()
+    }
+
+    #[must_use]
+    pub fn distance(self, point: Point4) -> f32 {
+        self.signed_distance(point).abs()
+    }
+
+    /// The angle between the normals, so hyperplanes facing opposite ways are half a turn apart, NaN if either normal is zero
+    #[must_use]
+    pub fn angle(self, other: Self) -> f32 {
+        self.normal().angle_between(other.normal())
+    }
+
+    /// The line through `point` that is perpendicular to this hyperplane
+    #[must_use]
+    pub const fn perpendicular_line(self, point: Point4) -> Line {
+        let Self {
+            e0: _,
+            e1,
+            e2,
+            e3,
+            e4,
+        } = self;
+        let Point4 { x, y, z, w } = point;
+        Line {
+            e012: e3 * w + e4 * z,
+            e013: e4 * y - e2 * w,
+            e014: -e2 * z - e3 * y,
+            e023: e1 * w + e4 * x,
+            e024: e1 * z - e3 * x,
+            e034: e1 * y + e2 * x,
+            e123: e4,
+            e124: -e3,
+            e134: e2,
+            e234: -e1,
+        }
+    }
+
+    /// The closest point on the hyperplane, a hyperplane with a zero normal has no points so this gives NaN
+    #[must_use]
+    pub const fn project(self, point: Point4) -> Point4 {
+        Point4::from_homogeneous(hyperplane_line_meet(self, self.perpendicular_line(point)))
+    }
+}
+
+/// The hyperplane containing the point and the plane
+impl Join<Plane> for Point4 {
+    type Output = Hyperplane;
+
+    fn join(self, plane: Plane) -> Hyperplane {
+        let Point4 { x, y, z, w } = self;
+        let Plane {
+            e01: b01,
+            e02: b02,
+            e03: b03,
+            e04: b04,
+            e12: b12,
+            e13: b13,
+            e14: b14,
+            e23: b23,
+            e24: b24,
+            e34: b34,
+        } = plane;
+        Hyperplane {
+            e0: b02 * y + b04 * w - b01 * x - b03 * z,
+            e1: b12 * y + b14 * w - b01 - b13 * z,
+            e2: b12 * x + b24 * w - b02 - b23 * z,
+            e3: b13 * x + b34 * w - b03 - b23 * y,
+            e4: b14 * x + b34 * z - b04 - b24 * y,
+        }
+    }
+}
+
+impl Join<Point4> for Plane {
+    type Output = Hyperplane;
+
+    fn join(self, point: Point4) -> Hyperplane {
+        point.join(self)
+    }
+}
+
+/// The hyperplane containing both lines, which is degenerate if they cross or are parallel
+impl Join for Line {
+    type Output = Hyperplane;
+
+    fn join(self, other: Self) -> Hyperplane {
+        let Self {
+            e012: a012,
+            e013: a013,
+            e014: a014,
+            e023: a023,
+            e024: a024,
+            e034: a034,
+            e123: a123,
+            e124: a124,
+            e134: a134,
+            e234: a234,
+        } = self;
+        let Self {
+            e012: b012,
+            e013: b013,
+            e014: b014,
+            e023: b023,
+            e024: b024,
+            e034: b034,
+            e123: b123,
+            e124: b124,
+            e134: b134,
+            e234: b234,
+        } = other;
+        Hyperplane {
+            e0: a012 * b034 + a014 * b023 + a023 * b014 + a034 * b012 - a013 * b024 - a024 * b013,
+            e1: a012 * b134 + a014 * b123 + a123 * b014 + a134 * b012 - a013 * b124 - a124 * b013,
+            e2: a012 * b234 + a024 * b123 + a123 * b024 + a234 * b012 - a023 * b124 - a124 * b023,
+            e3: a013 * b234 + a034 * b123 + a123 * b034 + a234 * b013 - a023 * b134 - a134 * b023,
+            e4: a014 * b234 + a034 * b124 + a124 * b034 + a234 * b014 - a024 * b134 - a134 * b024,
+        }
+    }
+}
+
+impl Mul<Hyperplane> for Transform {
+    type Output = Hyperplane;
+
+    fn mul(self, hyperplane: Hyperplane) -> Hyperplane {
+        Hyperplane::from_point_normal(
+            self * hyperplane.project(Point4::ORIGIN),
+            self * hyperplane.normal(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Meet;
+
+    const TOLERANCE: f32 = 1e-4;
+
+    fn points() -> [Point4; 4] {
+        [
+            Point4::ORIGIN,
+            Point4::new(1.0, -2.0, 3.5, 0.25),
+            Point4::new(-4.0, 0.5, 2.0, 7.0),
+            Point4::new(0.1, 0.2, -0.3, -6.0),
+        ]
+    }
+
+    fn hyperplanes() -> [Hyperplane; 3] {
+        [
+            Hyperplane::new(Vec4::X, 0.0),
+            Hyperplane::new(Vec4::new(0.0, 0.0, 3.0, 0.0), -2.0),
+            Hyperplane::from_point_normal(
+                Point4::new(1.0, 1.0, -1.0, 2.0),
+                Vec4::new(0.3, -1.2, 2.0, 0.7),
+            ),
+        ]
+    }
+
+    #[test]
+    fn signed_distance_matches_the_dot_product() {
+        let on_plane = Point4::new(1.0, 1.0, -1.0, 2.0);
+        let normal = Vec4::new(0.3, -1.2, 2.0, 0.7);
+        let hyperplane = Hyperplane::from_point_normal(on_plane, normal);
+        for point in points() {
+            let expected = (point - on_plane).dot(normal.normalised());
+            assert!((hyperplane.signed_distance(point) - expected).abs() < TOLERANCE);
+            assert!((hyperplane.distance(point) - expected.abs()).abs() < TOLERANCE);
+        }
+    }
+
+    #[test]
+    fn project_lands_on_the_hyperplane() {
+        for hyperplane in hyperplanes() {
+            for point in points() {
+                let projected = hyperplane.project(point);
+                assert!(hyperplane.signed_distance(projected).abs() < TOLERANCE);
+                assert!(
+                    hyperplane
+                        .project(projected)
+                        .abs_diff_eq(projected, TOLERANCE)
+                );
+                // the shortest way there is along the normal
+                let offset = point - projected;
+                let along_normal =
+                    hyperplane.normal().normalised() * hyperplane.signed_distance(point);
+                assert!(
+                    offset.abs_diff_eq(along_normal, TOLERANCE),
+                    "{hyperplane:?} {point:?}"
+                );
+                assert!(
+                    hyperplane
+                        .perpendicular_line(point)
+                        .direction()
+                        .normalised()
+                        .dot(hyperplane.normal().normalised())
+                        .abs()
+                        > 1.0 - TOLERANCE
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn join_then_meet_gives_back_the_incident_flats() {
+        let [_, a, b, c] = points();
+        let plane = Plane::from_point_direction(b, Vec4::Y.wedge(Vec4::new(0.0, 0.0, 1.0, 1.0)));
+        let hyperplane = a.join(plane);
+        for point in [a, b, b + Vec4::Y, b + Vec4::new(0.0, 0.0, 1.0, 1.0)] {
+            assert!(
+                hyperplane.signed_distance(point).abs() < TOLERANCE,
+                "{point:?}"
+            );
+        }
+        assert!(
+            plane
+                .join(a)
+                .normal()
+                .abs_diff_eq(hyperplane.normal(), TOLERANCE)
+        );
+
+        // two skew lines span a hyperplane containing both, lines that cross only span a plane
+        let first = Line::from_point_direction(a, Vec4::Y);
+        let second = Line::from_point_direction(c, Vec4::new(0.0, 0.0, 1.0, 0.5));
+        let hyperplane = first.join(second);
+        for point in [a, a + Vec4::Y, c, c + Vec4::new(0.0, 0.0, 1.0, 0.5)] {
+            assert!(
+                hyperplane.signed_distance(point).abs() < TOLERANCE,
+                "{point:?}"
+            );
+        }
+        // and any other line through `c` meets it back at `c`
+        let crossing = Line::from_point_direction(c, hyperplane.normal() + Vec4::X * 0.1);
+        assert!(hyperplane.meet(crossing).unwrap().abs_diff_eq(c, TOLERANCE));
+
+        // crossing a plane in it with another hyperplane gives back a line in the plane
+        let other = Hyperplane::from_point_normal(b, Vec4::Z);
+        let line = other.meet(plane);
+        for point in [b, b + Vec4::Y * 2.0] {
+            assert!(
+                line.project(point).abs_diff_eq(point, TOLERANCE),
+                "{point:?}"
+            );
+        }
+        assert!(
+            line.direction()
+                .normalised()
+                .abs_diff_eq(Vec4::Y, TOLERANCE)
+                || line
+                    .direction()
+                    .normalised()
+                    .abs_diff_eq(-Vec4::Y, TOLERANCE)
+        );
+    }
+
+    #[test]
+    fn angle_between_normals() {
+        let a = Hyperplane::new(Vec4::X, 1.0);
+        let b = Hyperplane::new(Vec4::new(1.0, 1.0, 0.0, 0.0), -3.0);
+        assert!((a.angle(b) - std::f32::consts::FRAC_PI_4).abs() < TOLERANCE);
+        assert!((a.angle(Hyperplane::new(-Vec4::X, 0.0)) - std::f32::consts::PI).abs() < TOLERANCE);
+    }
+}
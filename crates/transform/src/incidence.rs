@@ -0,0 +1,16 @@
+/// The smallest flat containing both, such as the line through two points
+pub trait Join<Rhs = Self> {
+    type Output;
+
+    #[must_use]
+    fn join(self, other: Rhs) -> Self::Output;
+}
+
+/// The largest flat contained in both, such as the plane where two hyperplanes cross,
+/// flats that dont cross anywhere meet at infinity, which gives a flat with a zero direction or no point at all
+pub trait Meet<Rhs = Self> {
+    type Output;
+
+    #[must_use]
+    fn meet(self, other: Rhs) -> Self::Output;
+}
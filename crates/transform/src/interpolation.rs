@@ -0,0 +1,193 @@
+use crate::{Transform, Vec4};
+use bevy::{
+    ecs::{
+        change_detection::DetectChanges,
+        component::Component,
+        query::With,
+        system::{Query, Res},
+        world::Mut,
+    },
+    reflect::{Reflect, prelude::ReflectDefault},
+    time::{Fixed, Time},
+};
+
+/// Computes the global transform of an entity moved in `FixedUpdate` between its poses before and after
+/// the last fixed timestep, so it doesnt stutter when frames and fixed timesteps dont line up,
+/// it is drawn up to one fixed timestep behind its [`Transform`]
+///
+/// Changes to the [`Transform`] outside of the fixed timestep show up once the next one starts
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[require(Transform, FixedStepTransforms)]
+pub struct InterpolateTransform;
+
+/// The poses of an [`InterpolateTransform`] entity around the last fixed timestep, relative to its [`crate::GridCell`]
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[reflect(Default, Clone)]
+pub struct FixedStepTransforms {
+    pub previous: Transform,
+    pub current: Transform,
+    /// The pose its global transform is computed from instead of its [`Transform`]
+    pub interpolated: Transform,
+}
+
+impl FixedStepTransforms {
+    /// Moves every pose by the same translation, for when the entity moves into another grid cell
+    pub(crate) fn translate(&mut self, translation: Vec4) {
+        for pose in [
+            &mut self.previous,
+            &mut self.current,
+            &mut self.interpolated,
+        ] {
+            *pose = pose.with_translation(pose.translation_part() + translation);
+        }
+    }
+}
+
+/// The pose the global transform of an entity is computed from, the interpolated one while it has [`InterpolateTransform`]
+pub(crate) fn propagated_transform<'a>(
+    transform: Option<&'a Transform>,
+    poses: Option<&'a FixedStepTransforms>,
+    interpolates: bool,
+) -> Option<&'a Transform> {
+    match poses {
+        Some(poses) if interpolates => Some(&poses.interpolated),
+        _ => transform,
+    }
+}
+
+/// The pose after the last fixed timestep while it has [`InterpolateTransform`], which is what its [`Transform`] is set from
+pub(crate) fn fixed_step_transform<'a>(
+    transform: Option<&'a Transform>,
+    poses: Option<&'a FixedStepTransforms>,
+    interpolates: bool,
+) -> Option<&'a Transform> {
+    match poses {
+        Some(poses) if interpolates => Some(&poses.current),
+        _ => transform,
+    }
+}
+
+pub(crate) fn start_fixed_step(
+    mut poses: Query<(&Transform, Mut<FixedStepTransforms>), With<InterpolateTransform>>,
+) {
+    poses.par_iter_mut().for_each(|(transform, mut poses)| {
+        let moved = FixedStepTransforms {
+            previous: *transform,
+            ..*poses
+        };
+        set_if_moved(&mut poses, moved);
+    });
+}
+
+pub(crate) fn finish_fixed_step(
+    mut poses: Query<(&Transform, Mut<FixedStepTransforms>), With<InterpolateTransform>>,
+) {
+    poses.par_iter_mut().for_each(|(transform, mut poses)| {
+        let moved = FixedStepTransforms {
+            current: *transform,
+            ..*poses
+        };
+        set_if_moved(&mut poses, moved);
+    });
+}
+
+/// Runs every frame whether or not a fixed timestep ran, as the time since the last one still moves on
+pub(crate) fn interpolate_transforms(
+    time: Res<Time<Fixed>>,
+    mut poses: Query<(&Transform, Mut<FixedStepTransforms>), With<InterpolateTransform>>,
+) {
+    let fraction = time.overstep_fraction();
+    poses.par_iter_mut().for_each(|(transform, mut poses)| {
+        // there is nothing to interpolate between until a fixed timestep has run
+        let (previous, current) = if poses.is_added() {
+            (*transform, *transform)
+        } else {
+            (poses.previous, poses.current)
+        };
+        // slerping between equal poses can still round differently for each fraction
+        let interpolated = if previous.abs_diff_eq(current, 0.0) {
+            current
+        } else {
+            previous.slerp(current, fraction)
+        };
+        let moved = FixedStepTransforms {
+            previous,
+            current,
+            interpolated,
+        };
+        set_if_moved(&mut poses, moved);
+    });
+}
+
+/// Only flags the poses as changed when one of them moved, otherwise every entity at rest would propagate every frame
+fn set_if_moved(poses: &mut Mut<FixedStepTransforms>, moved: FixedStepTransforms) {
+    let unmoved = poses.previous.abs_diff_eq(moved.previous, 0.0)
+        && poses.current.abs_diff_eq(moved.current, 0.0)
+        && poses.interpolated.abs_diff_eq(moved.interpolated, 0.0);
+    if !unmoved {
+        **poses = moved;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GlobalTransform, Point4, TransformPlugin};
+    use bevy::{
+        app::App,
+        ecs::hierarchy::ChildOf,
+        time::{TimePlugin, TimeUpdateStrategy},
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn entities_at_rest_dont_propagate_again() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, TransformPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(7)));
+        let parent = app
+            .world_mut()
+            .spawn((
+                Transform::translation(1.0, 0.0, 0.0, 0.0),
+                InterpolateTransform,
+            ))
+            .id();
+        let child = app
+            .world_mut()
+            .spawn((Transform::translation(0.0, 1.0, 0.0, 0.0), ChildOf(parent)))
+            .id();
+        // long enough for a few fixed timesteps to have run
+        for _ in 0..10 {
+            app.update();
+        }
+
+        let last_changed = |app: &App| {
+            let world = app.world();
+            [
+                world
+                    .entity(parent)
+                    .get_change_ticks::<FixedStepTransforms>(),
+                world.entity(parent).get_change_ticks::<GlobalTransform>(),
+                world.entity(child).get_change_ticks::<GlobalTransform>(),
+            ]
+            .map(|ticks| ticks.unwrap().changed)
+        };
+        let before = last_changed(&app);
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(last_changed(&app), before);
+
+        // and moving it again still interpolates
+        app.world_mut()
+            .entity_mut(parent)
+            .insert(Transform::translation(2.0, 0.0, 0.0, 0.0));
+        for _ in 0..10 {
+            app.update();
+        }
+        let position = *app.world().get::<GlobalTransform>(child).unwrap() * Point4::ORIGIN;
+        assert!(position.abs_diff_eq(Point4::new(2.0, 1.0, 0.0, 0.0), 1e-5));
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{Bivector, Rotor};
+
+/// A rotation split into two orthogonal planes that it rotates within, from [`Rotor::invariant_planes`]
+///
+/// The planes are unit bivectors, `Rotor::exp(plane.scaled(angle * 0.5))` rotates by `angle` within `plane`
+#[derive(Debug, Clone, Copy)]
+pub struct InvariantPlanes {
+    pub first: Bivector,
+    pub first_angle: f32,
+    pub second: Bivector,
+    pub second_angle: f32,
+    /// When both planes rotate by the same angle the split isnt unique,
+    /// every plane through the origin is rotated by that angle
+    pub isoclinic: Option<Isoclinic>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Isoclinic {
+    Left,
+    Right,
+    /// Both left and right isoclinic, this is only the identity or the central inversion
+    Both,
+}
+
+impl From<InvariantPlanes> for Rotor {
+    fn from(planes: InvariantPlanes) -> Self {
+        let InvariantPlanes {
+            first,
+            first_angle,
+            second,
+            second_angle,
+            isoclinic: _,
+        } = planes;
+        // the planes are orthogonal, so these rotations commute
+        Self::exp(first.scaled(first_angle * 0.5))
+            .then(Self::exp(second.scaled(second_angle * 0.5)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::rotors;
+    use std::f32::consts::PI;
+
+    const TOLERANCE: f32 = 1e-4;
+
+    #[test]
+    fn invariant_planes_round_trip() {
+        for rotor in rotors() {
+            assert!(
+                Rotor::from(rotor.invariant_planes()).approx_eq(rotor, TOLERANCE),
+                "{rotor:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn invariant_plane_angles() {
+        let planes = Rotor::rotation_zw(0.5)
+            .then(Rotor::rotation_xy(2.0))
+            .invariant_planes();
+        assert!((planes.first_angle - 2.0).abs() < TOLERANCE);
+        assert!((planes.second_angle - 0.5).abs() < TOLERANCE);
+        assert_eq!(planes.isoclinic, None);
+
+        let planes = Rotor::rotation_xz(PI).invariant_planes();
+        assert!((planes.first_angle - PI).abs() < TOLERANCE);
+        assert!(planes.second_angle.abs() < TOLERANCE);
+    }
+
+    #[test]
+    fn isoclinic_detection() {
+        let isoclinic = |rotor: Rotor| rotor.invariant_planes().isoclinic;
+        assert_eq!(isoclinic(Rotor::IDENTITY), Some(Isoclinic::Both));
+        assert_eq!(isoclinic(Rotor::rotation_xy(1e-6)), Some(Isoclinic::Both));
+        assert_eq!(
+            isoclinic(Rotor::rotation_xy(PI).then(Rotor::rotation_zw(PI))),
+            Some(Isoclinic::Both)
+        );
+        assert_eq!(
+            isoclinic(Rotor::left_isoclinic(Bivector::rotation_xy(1.0), 0.7)),
+            Some(Isoclinic::Left)
+        );
+        assert_eq!(
+            isoclinic(Rotor::right_isoclinic(Bivector::rotation_xy(1.0), 0.7)),
+            Some(Isoclinic::Right)
+        );
+        assert_eq!(isoclinic(Rotor::rotation_xy(1.0)), None);
+
+        // angles closer than the tolerance still count as isoclinic, further apart they dont
+        let equal_angles = |difference: f32| {
+            isoclinic(Rotor::rotation_xy(1.0).then(Rotor::rotation_zw(1.0 + difference)))
+        };
+        assert!(equal_angles(1e-5).is_some());
+        assert_eq!(equal_angles(1e-2), None);
+    }
+}
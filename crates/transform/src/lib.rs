@@ -1,125 +1,690 @@
 use bevy::{
-    app::{App, Plugin, PostStartup, PostUpdate},
+    app::{App, FixedFirst, FixedLast, FixedUpdate, Plugin, PostStartup, PostUpdate},
     ecs::{
         change_detection::{DetectChanges, DetectChangesMut},
-        component::Component,
-        entity::Entity,
-        hierarchy::ChildOf,
-        query::{Changed, Or, With, Without},
+        entity::{Entity, EntityHashSet},
+        hierarchy::{ChildOf, Children},
+        query::{Changed, Has, Or, With, Without},
         removal_detection::RemovedComponents,
-        schedule::IntoScheduleConfigs,
-        system::Query,
-        world::Ref,
+        schedule::{IntoScheduleConfigs, common_conditions::resource_exists},
+        system::{Local, Query, Res, SystemChangeTick},
+        world::{Mut, Ref},
     },
-    reflect::{Reflect, prelude::ReflectDefault},
+    time::{Fixed, Time},
 };
 
+mod bivector;
+mod commands;
+mod constraints;
+mod dpoint;
+mod drotor;
+mod dtransform;
+mod dvector;
+mod global_transform;
+mod grid_cell;
+mod helper;
+mod hyperplane;
+mod incidence;
+mod interpolation;
+mod invariant_planes;
+mod line;
+mod plane;
+mod point;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "serde")]
+pub mod readable;
+mod reflection;
 mod rotor;
+mod scale;
+mod screw;
+mod simd;
+#[cfg(test)]
+mod test_fixtures;
 mod transform;
+mod vector;
+mod velocity;
 
+pub use bivector::Bivector;
+pub use commands::ReparentInPlace;
+pub use constraints::{Follow, LookAt4, Orbit4};
+pub use dpoint::DPoint4;
+pub use drotor::DRotor;
+pub use dtransform::DTransform;
+pub use dvector::DVec4;
+pub use global_transform::{GlobalTransform, TransformTreeChanged};
+pub use grid_cell::{GlobalGridCell, GridCell, GridSettings};
+pub use helper::{ComputeGlobalTransformError, TransformHelper};
+pub use hyperplane::Hyperplane;
+pub use incidence::{Join, Meet};
+pub use interpolation::{FixedStepTransforms, InterpolateTransform};
+pub use invariant_planes::{InvariantPlanes, Isoclinic};
+pub use line::Line;
+pub use plane::Plane;
+pub use point::Point4;
+pub use reflection::Reflection;
 pub use rotor::Rotor;
+pub use scale::{GlobalScale, Scale};
+pub use screw::Screw;
 pub use transform::Transform;
+pub use vector::Vec4;
+pub use velocity::{AngularVelocity4, Velocity4};
 
 pub struct TransformPlugin;
 
 impl Plugin for TransformPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Transform>()
-            .register_type::<GlobalTransform>();
+            .register_type::<Reflection>()
+            .register_type::<Scale>()
+            .register_type::<GlobalTransform>()
+            .register_type::<GlobalScale>()
+            .register_type::<TransformTreeChanged>()
+            .register_type::<GridCell>()
+            .register_type::<GlobalGridCell>()
+            .register_type::<GridSettings>()
+            .register_type::<Velocity4>()
+            .register_type::<AngularVelocity4>()
+            .register_type::<InterpolateTransform>()
+            .register_type::<FixedStepTransforms>()
+            .register_type::<LookAt4>()
+            .register_type::<Follow>()
+            .register_type::<Orbit4>()
+            .init_resource::<GridSettings>();
+
+        app.add_systems(FixedFirst, interpolation::start_fixed_step)
+            .add_systems(FixedUpdate, velocity::integrate_velocities)
+            .add_systems(FixedLast, interpolation::finish_fixed_step);
 
         app.add_systems(
             PostStartup,
             (
-                (normalise_transforms, flag_orphans),
-                (
-                    compute_global_transform_parents,
-                    compute_global_transform_children,
-                ),
+                constraints::orbit.run_if(resource_exists::<Time>),
+                constraints::follow.run_if(resource_exists::<Time>),
+                constraints::look_at,
+                normalise_transforms,
+                recentre_grid_cells,
+                interpolation::interpolate_transforms.run_if(resource_exists::<Time<Fixed>>),
+                mark_dirty_trees,
+                propagate_global_transforms,
             )
                 .chain(),
         )
         .add_systems(
             PostUpdate,
             (
-                (normalise_transforms, flag_orphans),
-                (
-                    compute_global_transform_parents,
-                    compute_global_transform_children,
-                ),
+                constraints::orbit.run_if(resource_exists::<Time>),
+                constraints::follow.run_if(resource_exists::<Time>),
+                constraints::look_at,
+                normalise_transforms,
+                recentre_grid_cells,
+                interpolation::interpolate_transforms.run_if(resource_exists::<Time<Fixed>>),
+                mark_dirty_trees,
+                propagate_global_transforms,
             )
                 .chain(),
         );
     }
 }
 
-#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
-#[reflect(Default, Clone)]
-pub struct GlobalTransform(pub Transform);
-
-fn normalise_transforms(mut transforms: Query<&mut Transform, Changed<Transform>>) {
+fn normalise_transforms(
+    mut transforms: Query<&mut Transform, Changed<Transform>>,
+    mut reflections: Query<&mut Reflection, Changed<Reflection>>,
+) {
     transforms
         .par_iter_mut()
         .for_each(|mut transform| *transform = transform.normalised());
+    reflections
+        .par_iter_mut()
+        .for_each(|mut reflection| *reflection = reflection.normalised());
 }
 
-fn flag_orphans(
+/// Moves roots that have left their [`GridCell`] into the cell they are in now,
+/// after [`normalise_transforms`] so the translation it splits off is the one of a rigid motion
+fn recentre_grid_cells(
+    settings: Res<GridSettings>,
+    mut roots: Query<
+        (
+            &mut Transform,
+            &mut GridCell,
+            Option<&mut FixedStepTransforms>,
+        ),
+        (Changed<Transform>, Without<ChildOf>),
+    >,
+) {
+    roots
+        .par_iter_mut()
+        .for_each(|(mut transform, mut grid_cell, poses)| {
+            let translation = transform.translation_part();
+            let offset = GridCell::containing(translation, settings.cell_size);
+            if offset != GridCell::ZERO {
+                *grid_cell = *grid_cell + offset;
+                let shift = offset
+                    .offset_from(GridCell::ZERO, settings.cell_size)
+                    .as_vec4();
+                *transform = transform.with_translation(translation - shift);
+                // the poses being interpolated between have to stay in the same cell as the transform
+                if let Some(mut poses) = poses {
+                    poses.translate(-shift);
+                }
+            }
+        });
+}
+
+/// Flags the trees above every entity whose global transform has to be recomputed
+fn mark_dirty_trees(
+    changed: Query<
+        Entity,
+        Or<(
+            Changed<Transform>,
+            Changed<Reflection>,
+            Changed<Scale>,
+            Changed<GridCell>,
+            Changed<FixedStepTransforms>,
+            Changed<ChildOf>,
+        )>,
+    >,
     mut global_transforms: Query<&mut GlobalTransform>,
+    mut trees: Query<(Option<&ChildOf>, Option<Mut<TransformTreeChanged>>)>,
     mut orphaned: RemovedComponents<ChildOf>,
+    mut unmirrored: RemovedComponents<Reflection>,
+    mut unscaled: RemovedComponents<Scale>,
+    mut ungridded: RemovedComponents<GridCell>,
+    mut uninterpolated: RemovedComponents<InterpolateTransform>,
+    mut passed: Local<EntityHashSet>,
+    ticks: SystemChangeTick,
 ) {
-    // set the changed flag on all orphaned entities so their transforms will be updated
-    let mut orphaned = global_transforms.iter_many_mut(orphaned.read());
-    while let Some(mut global_transform) = orphaned.fetch_next() {
+    // nothing else on the entity changes when one of these is removed, so flag its global transform instead
+    let removed = orphaned
+        .read()
+        .chain(unmirrored.read())
+        .chain(unscaled.read())
+        .chain(ungridded.read())
+        .chain(uninterpolated.read())
+        .collect::<Vec<_>>();
+    let mut flagged = global_transforms.iter_many_mut(&removed);
+    while let Some(mut global_transform) = flagged.fetch_next() {
         global_transform.set_changed();
     }
+
+    passed.clear();
+    for entity in changed.iter().chain(removed) {
+        let mut next = entity;
+        while let Ok((child_of, tree)) = trees.get_mut(next) {
+            match tree {
+                // the rest of the way up was already flagged, this also stops at ChildOf cycles
+                Some(tree) if tree.last_changed() == ticks.this_run() => break,
+                Some(mut tree) => tree.set_changed(),
+                // entities without a global transform have no flag but their parents still need one
+                None if !passed.insert(next) => break,
+                None => {}
+            }
+
+            let Some(child_of) = child_of else {
+                break;
+            };
+            next = child_of.0;
+        }
+    }
 }
 
-fn compute_global_transform_parents(
-    mut global_transforms: Query<
-        (&Transform, &mut GlobalTransform),
+/// The scale is applied first, then the reflection and then the transform
+pub(crate) fn local_transform(
+    transform: Option<&Transform>,
+    reflection: Option<&Reflection>,
+    scale: Option<&Scale>,
+) -> (GlobalTransform, Scale) {
+    let transform = match (transform, reflection) {
+        (Some(&transform), Some(&reflection)) => (transform * reflection).into(),
+        (Some(&transform), None) => transform.into(),
+        (None, Some(&reflection)) => reflection.into(),
+        (None, None) => GlobalTransform::IDENTITY,
+    };
+    (transform, scale.copied().unwrap_or_default())
+}
+
+/// Walks down from every root in parallel, only entering subtrees flagged by [`mark_dirty_trees`],
+/// entities in a ChildOf cycle cant be reached from a root so they are left alone.
+/// Entities without a [`GlobalTransform`] are always walked through and pass their transform on like [`TransformHelper`] does
+fn propagate_global_transforms(
+    roots: Query<
+        Entity,
         (
-            Or<(Changed<Transform>, Changed<GlobalTransform>)>,
             Without<ChildOf>,
+            Or<(With<GlobalTransform>, With<Children>)>,
         ),
     >,
+    parents: Query<&ChildOf>,
+    nodes: Query<(
+        Option<Ref<Transform>>,
+        Option<Ref<Reflection>>,
+        Option<Ref<Scale>>,
+        Option<Ref<GridCell>>,
+        Option<Ref<FixedStepTransforms>>,
+        Has<InterpolateTransform>,
+        Option<Ref<ChildOf>>,
+        Option<(
+            Mut<GlobalTransform>,
+            &mut GlobalScale,
+            &mut GlobalGridCell,
+            Ref<TransformTreeChanged>,
+        )>,
+        Option<&Children>,
+    )>,
 ) {
-    global_transforms
-        .par_iter_mut()
-        .for_each(|(transform, mut global_transform)| {
-            *global_transform = GlobalTransform(*transform);
-        })
-}
+    roots.par_iter().for_each_init(Vec::new, |stack, root| {
+        stack.push((
+            root,
+            GlobalTransform::IDENTITY,
+            Scale::ONE,
+            GridCell::ZERO,
+            false,
+        ));
+        while let Some((entity, parent_transform, parent_scale, parent_cell, parent_changed)) =
+            stack.pop()
+        {
+            // SAFETY: every entity is fetched by one task at most. roots have no ChildOf so they are only
+            // reached as roots, and every child is only pushed by the parent its ChildOf points at,
+            // so two roots can never reach the same entity, and cycles cant be reached at all
+            let Ok((
+                transform,
+                reflection,
+                scale,
+                grid_cell,
+                poses,
+                interpolates,
+                child_of,
+                globals,
+                children,
+            )) = (unsafe { nodes.get_unchecked(entity) })
+            else {
+                continue;
+            };
+            if let Some((.., tree)) = &globals
+                && !parent_changed
+                && !tree.is_changed()
+            {
+                continue;
+            }
 
-// TODO: improve this to not run at all on entities which havent changed
-fn compute_global_transform_children(
-    mut global_transforms: Query<(Entity, &mut GlobalTransform), With<ChildOf>>,
-    transforms: Query<(Option<Ref<Transform>>, Option<Ref<ChildOf>>)>,
-) {
-    global_transforms
-        .par_iter_mut()
-        .for_each(|(entity, mut global_transform)| {
-            let mut computed_transform = Transform::default();
-
-            let mut changed = global_transform.is_changed();
-            let mut next = entity;
-            loop {
-                let Ok((transform, child_of)) = transforms.get(next) else {
-                    unreachable!()
-                };
-
-                if let Some(transform) = transform {
-                    changed |= transform.is_changed();
-                    computed_transform = transform.then(computed_transform);
+            let changed = parent_changed
+                || globals
+                    .as_ref()
+                    .is_some_and(|(global_transform, ..)| global_transform.is_changed())
+                || transform.as_ref().is_some_and(DetectChanges::is_changed)
+                || reflection.as_ref().is_some_and(DetectChanges::is_changed)
+                || scale.as_ref().is_some_and(DetectChanges::is_changed)
+                || grid_cell.as_ref().is_some_and(DetectChanges::is_changed)
+                || poses.as_ref().is_some_and(DetectChanges::is_changed)
+                || child_of.as_ref().is_some_and(DetectChanges::is_changed);
+            let global = || {
+                let transform = interpolation::propagated_transform(
+                    transform.as_deref(),
+                    poses.as_deref(),
+                    interpolates,
+                );
+                let (transform, scale) =
+                    local_transform(transform, reflection.as_deref(), scale.as_deref());
+                (
+                    // the parent scale applies to everything below it, including their translations
+                    parent_transform
+                        .then(transform.with_scaled_translation(parent_scale))
+                        .normalised(),
+                    parent_scale.then(scale),
+                    // only roots have their own cell, a child is always in the cell of its root
+                    match (&child_of, &grid_cell) {
+                        (None, Some(grid_cell)) => **grid_cell,
+                        _ => parent_cell,
+                    },
+                )
+            };
+            let (global_transform, global_scale, global_grid_cell) = match globals {
+                Some((mut global_transform, mut global_scale, mut global_grid_cell, _)) => {
+                    if changed {
+                        let (transform, scale, grid_cell) = global();
+                        *global_transform = transform;
+                        *global_scale = GlobalScale(scale);
+                        *global_grid_cell = GlobalGridCell(grid_cell);
+                    }
+                    (*global_transform, global_scale.0, global_grid_cell.0)
                 }
+                // theres nowhere to store it, but the children still need it
+                None => global(),
+            };
 
-                let Some(child_of) = child_of else {
-                    break;
-                };
-                changed |= child_of.is_changed();
-                next = child_of.0;
+            if let Some(children) = children {
+                stack.extend(
+                    children
+                        .iter()
+                        .filter(|&&child| parents.get(child).is_ok_and(|parent| parent.0 == entity))
+                        .map(|&child| {
+                            (
+                                child,
+                                global_transform,
+                                global_scale,
+                                global_grid_cell,
+                                changed,
+                            )
+                        }),
+                );
             }
+        }
+    });
+}
 
-            if changed {
-                *global_transform = GlobalTransform(computed_transform.normalised());
-            }
-        })
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::component::Tick;
+
+    const TOLERANCE: f32 = 1e-5;
+
+    fn global_position(app: &App, entity: Entity) -> Point4 {
+        *app.world().get::<GlobalTransform>(entity).unwrap() * Point4::ORIGIN
+    }
+
+    fn last_propagated(app: &App, entity: Entity) -> Tick {
+        app.world()
+            .entity(entity)
+            .get_change_ticks::<GlobalTransform>()
+            .unwrap()
+            .changed
+    }
+
+    #[test]
+    fn unchanged_subtrees_are_skipped() {
+        let mut app = App::new();
+        app.add_plugins(TransformPlugin);
+        let moving = app
+            .world_mut()
+            .spawn(Transform::translation(1.0, 0.0, 0.0, 0.0))
+            .id();
+        let moving_child = app
+            .world_mut()
+            .spawn((Transform::translation(0.0, 1.0, 0.0, 0.0), ChildOf(moving)))
+            .id();
+        let still = app
+            .world_mut()
+            .spawn(Transform::translation(0.0, 0.0, 1.0, 0.0))
+            .id();
+        let still_child = app
+            .world_mut()
+            .spawn((Transform::translation(0.0, 0.0, 0.0, 1.0), ChildOf(still)))
+            .id();
+        app.update();
+        let (still_tick, still_child_tick) = (
+            last_propagated(&app, still),
+            last_propagated(&app, still_child),
+        );
+        let moving_child_tick = last_propagated(&app, moving_child);
+
+        app.world_mut()
+            .entity_mut(moving)
+            .insert(Transform::translation(2.0, 0.0, 0.0, 0.0));
+        app.update();
+
+        assert_eq!(last_propagated(&app, still), still_tick);
+        assert_eq!(last_propagated(&app, still_child), still_child_tick);
+        assert_ne!(last_propagated(&app, moving_child), moving_child_tick);
+        assert!(
+            global_position(&app, moving_child)
+                .abs_diff_eq(Point4::new(2.0, 1.0, 0.0, 0.0), TOLERANCE)
+        );
+    }
+
+    #[test]
+    fn parent_scale_and_rotation_apply_to_children() {
+        let mut app = App::new();
+        app.add_plugins(TransformPlugin);
+        let quarter_turn = Transform::from_translation_rotor(
+            Vec4::new(1.0, 0.0, 0.0, 0.0),
+            Rotor::rotation_xy(std::f32::consts::FRAC_PI_2),
+        );
+        let uniform = app
+            .world_mut()
+            .spawn((quarter_turn, Scale::Uniform(2.0)))
+            .id();
+        let uniform_child = app
+            .world_mut()
+            .spawn((Transform::translation(1.0, 0.0, 0.0, 0.0), ChildOf(uniform)))
+            .id();
+        let per_axis = app
+            .world_mut()
+            .spawn((quarter_turn, Scale::PerAxis(Vec4::new(2.0, 3.0, 1.0, 1.0))))
+            .id();
+        let per_axis_child = app
+            .world_mut()
+            .spawn((
+                Transform::translation(0.0, 1.0, 0.0, 1.0),
+                Scale::Uniform(0.5),
+                ChildOf(per_axis),
+            ))
+            .id();
+        app.update();
+
+        // the offset from the parent is scaled and then turned by the parent
+        assert!(
+            global_position(&app, uniform_child)
+                .abs_diff_eq(Point4::new(1.0, 2.0, 0.0, 0.0), TOLERANCE)
+        );
+        assert_eq!(
+            app.world().get::<GlobalScale>(uniform_child),
+            Some(&GlobalScale(Scale::Uniform(2.0)))
+        );
+        assert!(
+            global_position(&app, per_axis_child)
+                .abs_diff_eq(Point4::new(-2.0, 0.0, 0.0, 1.0), TOLERANCE)
+        );
+        assert_eq!(
+            app.world().get::<GlobalScale>(per_axis_child),
+            Some(&GlobalScale(Scale::PerAxis(Vec4::new(1.0, 1.5, 0.5, 0.5))))
+        );
+    }
+
+    #[test]
+    fn reparenting_and_orphaning_update_children() {
+        let mut app = App::new();
+        app.add_plugins(TransformPlugin);
+        let first = app
+            .world_mut()
+            .spawn(Transform::translation(1.0, 0.0, 0.0, 0.0))
+            .id();
+        let second = app
+            .world_mut()
+            .spawn(Transform::translation(0.0, 2.0, 0.0, 0.0))
+            .id();
+        let child = app
+            .world_mut()
+            .spawn((Transform::translation(0.0, 0.0, 3.0, 0.0), ChildOf(first)))
+            .id();
+        app.update();
+        assert!(
+            global_position(&app, child).abs_diff_eq(Point4::new(1.0, 0.0, 3.0, 0.0), TOLERANCE)
+        );
+
+        app.world_mut().entity_mut(child).insert(ChildOf(second));
+        app.update();
+        assert!(
+            global_position(&app, child).abs_diff_eq(Point4::new(0.0, 2.0, 3.0, 0.0), TOLERANCE)
+        );
+
+        app.world_mut().entity_mut(child).remove::<ChildOf>();
+        app.update();
+        assert!(
+            global_position(&app, child).abs_diff_eq(Point4::new(0.0, 0.0, 3.0, 0.0), TOLERANCE)
+        );
+    }
+
+    #[test]
+    fn parents_without_global_transforms_are_walked_through() {
+        let mut app = App::new();
+        app.add_plugins(TransformPlugin);
+        // a plain grouping entity counts as the identity
+        let group = app.world_mut().spawn_empty().id();
+        let grouped = app
+            .world_mut()
+            .spawn((Transform::translation(0.0, 1.0, 0.0, 0.0), ChildOf(group)))
+            .id();
+        // a transform without a global transform still moves its children, like TransformHelper
+        let root = app
+            .world_mut()
+            .spawn(Transform::translation(1.0, 0.0, 0.0, 0.0))
+            .remove::<GlobalTransform>()
+            .id();
+        let middle = app
+            .world_mut()
+            .spawn((Transform::translation(0.0, 0.0, 2.0, 0.0), ChildOf(root)))
+            .id();
+        let child = app
+            .world_mut()
+            .spawn((Transform::translation(0.0, 3.0, 0.0, 0.0), ChildOf(middle)))
+            .id();
+        app.world_mut()
+            .entity_mut(middle)
+            .remove::<GlobalTransform>();
+        app.update();
+        assert!(
+            global_position(&app, grouped).abs_diff_eq(Point4::new(0.0, 1.0, 0.0, 0.0), TOLERANCE)
+        );
+        assert!(
+            global_position(&app, child).abs_diff_eq(Point4::new(1.0, 3.0, 2.0, 0.0), TOLERANCE)
+        );
+
+        // changes above and below the missing global transforms still get through
+        app.world_mut()
+            .entity_mut(root)
+            .insert(Transform::translation(5.0, 0.0, 0.0, 0.0));
+        app.update();
+        assert!(
+            global_position(&app, child).abs_diff_eq(Point4::new(5.0, 3.0, 2.0, 0.0), TOLERANCE)
+        );
+        app.world_mut()
+            .entity_mut(child)
+            .insert(Transform::translation(0.0, 4.0, 0.0, 0.0));
+        app.update();
+        assert!(
+            global_position(&app, child).abs_diff_eq(Point4::new(5.0, 4.0, 2.0, 0.0), TOLERANCE)
+        );
+    }
+
+    #[test]
+    fn roots_move_into_the_cell_they_reach() {
+        let mut app = App::new();
+        app.add_plugins(TransformPlugin);
+        let cell_size = app.world().resource::<GridSettings>().cell_size;
+        let root = app
+            .world_mut()
+            .spawn((Transform::IDENTITY, GridCell::new(3, 0, 0, -1)))
+            .id();
+        let child = app
+            .world_mut()
+            .spawn((Transform::translation(0.0, 1.0, 0.0, 0.0), ChildOf(root)))
+            .id();
+        app.update();
+
+        // just past half a cell along x and more than one cell back along y
+        let translation = Vec4::new(0.6, -1.2, 0.25, 0.0) * cell_size;
+        app.world_mut()
+            .entity_mut(root)
+            .insert(Transform::IDENTITY.with_translation(translation));
+        app.update();
+
+        assert_eq!(
+            app.world().get::<GridCell>(root),
+            Some(&GridCell::new(4, -1, 0, -1))
+        );
+        let wrapped = app
+            .world()
+            .get::<Transform>(root)
+            .unwrap()
+            .translation_part();
+        assert!(wrapped.abs_diff_eq(Vec4::new(-0.4, -0.2, 0.25, 0.0) * cell_size, 1e-3));
+        // the same place in the world, and the child moves into the new cell with it
+        for (entity, offset) in [(root, Vec4::ZERO), (child, Vec4::Y)] {
+            let GlobalGridCell(cell) = *app.world().get::<GlobalGridCell>(entity).unwrap();
+            assert_eq!(cell, GridCell::new(4, -1, 0, -1));
+            let world_position = cell
+                .offset_from(GridCell::new(3, 0, 0, -1), cell_size)
+                .as_vec4()
+                + global_position(&app, entity).to_vector();
+            assert!(world_position.abs_diff_eq(translation + offset, 1e-3));
+        }
+    }
+
+    #[test]
+    fn child_of_cycles_are_left_alone() {
+        let mut app = App::new();
+        app.add_plugins(TransformPlugin);
+        let root = app
+            .world_mut()
+            .spawn(Transform::translation(1.0, 0.0, 0.0, 0.0))
+            .id();
+        let [a, b] = [(); 2].map(|()| {
+            app.world_mut()
+                .spawn(Transform::translation(0.0, 1.0, 0.0, 0.0))
+                .id()
+        });
+        app.world_mut().entity_mut(a).insert(ChildOf(b));
+        app.world_mut().entity_mut(b).insert(ChildOf(a));
+        app.update();
+
+        // moving a member of the cycle later doesnt hang either, and the rest of the world still propagates
+        app.world_mut()
+            .entity_mut(a)
+            .insert(Transform::translation(0.0, 5.0, 0.0, 0.0));
+        app.world_mut()
+            .entity_mut(root)
+            .insert(Transform::translation(3.0, 0.0, 0.0, 0.0));
+        app.update();
+        assert!(
+            global_position(&app, root).abs_diff_eq(Point4::new(3.0, 0.0, 0.0, 0.0), TOLERANCE)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn components_round_trip_through_serde() {
+        fn round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(value: &T) -> T {
+            ron::from_str(&ron::to_string(value).unwrap()).unwrap()
+        }
+
+        let transform = Transform::from_translation_rotor(
+            Vec4::new(1.0, -2.0, 3.5, 0.25),
+            Rotor::rotation_xw(0.7),
+        );
+        assert!(round_trip(&transform).abs_diff_eq(transform, 0.0));
+        let grid_cell = GridCell::new(i64::MAX, -3, 0, 1 << 40);
+        assert_eq!(round_trip(&grid_cell), grid_cell);
+        assert_eq!(
+            round_trip(&Scale::PerAxis(Vec4::new(1.0, 2.0, 3.0, 4.0))),
+            Scale::PerAxis(Vec4::new(1.0, 2.0, 3.0, 4.0))
+        );
+
+        let target = Entity::from_raw(42);
+        let look_at = round_trip(&LookAt4::new(target));
+        assert_eq!(
+            (look_at.target, look_at.up, look_at.over),
+            (target, Vec4::Y, Vec4::W)
+        );
+        let follow = round_trip(&Follow {
+            target,
+            offset: Vec4::new(0.0, 1.0, -2.0, 0.5),
+            damping: 0.25,
+        });
+        assert_eq!(
+            (follow.target, follow.offset, follow.damping),
+            (target, Vec4::new(0.0, 1.0, -2.0, 0.5), 0.25)
+        );
+        let orbit = Orbit4 {
+            center: Point4::new(1.0, 0.0, 0.0, 0.0),
+            plane: [Vec4::X, Vec4::W],
+            radius: 3.0,
+            period: -2.0,
+        };
+        let out = round_trip(&orbit);
+        assert_eq!(
+            (out.center, out.plane, out.radius, out.period),
+            (orbit.center, orbit.plane, orbit.radius, orbit.period)
+        );
+    }
 }
@@ -0,0 +1,276 @@
+use crate::{Hyperplane, Join, Meet, Plane, Point4, Transform, Vec4, point::hyperplane_line_meet};
+use bevy::reflect::{Reflect, prelude::ReflectDefault};
+use bytemuck::{Pod, Zeroable};
+use std::ops::Mul;
+
+/// A line in 4D space, stored as a trivector of 4D PGA
+#[derive(Reflect, Debug, Default, Clone, Copy, Zeroable, Pod)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[repr(C)]
+pub struct Line {
+    pub e012: f32,
+    pub e013: f32,
+    pub e014: f32,
+    pub e023: f32,
+    pub e024: f32,
+    pub e034: f32,
+    pub e123: f32,
+    pub e124: f32,
+    pub e134: f32,
+    pub e234: f32,
+}
+
+impl Line {
+    #[must_use]
+    pub const fn from_point_direction(point: Point4, direction: Vec4) -> Self {
+        let Point4 { x, y, z, w } = point;
+        let Vec4 {
+            x: u0,
+            y: u1,
+            z: u2,
+            w: u3,
+        } = direction;
+        Self {
+            e012: w * u2 - z * u3,
+            e013: w * u1 - y * u3,
+            e014: z * u1 - y * u2,
+            e023: w * u0 - x * u3,
+            e024: z * u0 - x * u2,
+            e034: y * u0 - x * u1,
+            e123: -u3,
+            e124: -u2,
+            e134: -u1,
+            e234: -u0,
+        }
+    }
+
+    #[must_use]
+    pub const fn direction(self) -> Vec4 {
+        Vec4::new(-self.e234, -self.e134, -self.e124, -self.e123)
+    }
+
+    /// Scales the line so its direction has a length of 1
+    #[must_use]
+    pub fn normalised(self) -> Self {
+        let inverse_length = self.direction().length().recip();
+        let Self {
+            e012,
+            e013,
+            e014,
+            e023,
+            e024,
+            e034,
+            e123,
+            e124,
+            e134,
+            e234,
+        } = self;
+        Self {
+            e012: e012 * inverse_length,
+            e013: e013 * inverse_length,
+            e014: e014 * inverse_length,
+            e023: e023 * inverse_length,
+            e024: e024 * inverse_length,
+            e034: e034 * inverse_length,
+            e123: e123 * inverse_length,
+            e124: e124 * inverse_length,
+            e134: e134 * inverse_length,
+            e234: e234 * inverse_length,
+        }
+    }
+
+    /// NaN if the direction is zero, like [`Self::project`]
+    #[must_use]
+    pub fn distance(self, point: Point4) -> f32 {
+        point.distance(self.project(point))
+    }
+
+    /// The angle between the directions, so lines pointing opposite ways are half a turn apart, NaN if either direction is zero
+    #[must_use]
+    pub fn angle(self, other: Self) -> f32 {
+        self.direction().angle_between(other.direction())
+    }
+
+    /// The hyperplane through `point` that is perpendicular to this line
+    #[must_use]
+    pub const fn perpendicular_hyperplane(self, point: Point4) -> Hyperplane {
+        let Self {
+            e123,
+            e124,
+            e134,
+            e234,
+            ..
+        } = self;
+        let Point4 { x, y, z, w } = point;
+        Hyperplane {
+            e0: -e123 * w - e124 * z - e134 * y - e234 * x,
+            e1: -e234,
+            e2: e134,
+            e3: -e124,
+            e4: e123,
+        }
+    }
+
+    /// The closest point on the line, a line with a zero direction has no points so this gives NaN
+    #[must_use]
+    pub const fn project(self, point: Point4) -> Point4 {
+        Point4::from_homogeneous(hyperplane_line_meet(
+            self.perpendicular_hyperplane(point),
+            self,
+        ))
+    }
+}
+
+/// The line from this point towards the other
+impl Join for Point4 {
+    type Output = Line;
+
+    fn join(self, other: Self) -> Line {
+        Line::from_point_direction(self, other - self)
+    }
+}
+
+/// The line where the hyperplane crosses the plane
+impl Meet<Plane> for Hyperplane {
+    type Output = Line;
+
+    fn meet(self, plane: Plane) -> Line {
+        let Self {
+            e0: a0,
+            e1: a1,
+            e2: a2,
+            e3: a3,
+            e4: a4,
+        } = self;
+        let Plane {
+            e01: b01,
+            e02: b02,
+            e03: b03,
+            e04: b04,
+            e12: b12,
+            e13: b13,
+            e14: b14,
+            e23: b23,
+            e24: b24,
+            e34: b34,
+        } = plane;
+        Line {
+            e012: a0 * b12 + a2 * b01 - a1 * b02,
+            e013: a0 * b13 + a3 * b01 - a1 * b03,
+            e014: a0 * b14 + a4 * b01 - a1 * b04,
+            e023: a0 * b23 + a3 * b02 - a2 * b03,
+            e024: a0 * b24 + a4 * b02 - a2 * b04,
+            e034: a0 * b34 + a4 * b03 - a3 * b04,
+            e123: a1 * b23 + a3 * b12 - a2 * b13,
+            e124: a1 * b24 + a4 * b12 - a2 * b14,
+            e134: a1 * b34 + a4 * b13 - a3 * b14,
+            e234: a2 * b34 + a4 * b23 - a3 * b24,
+        }
+    }
+}
+
+impl Meet<Hyperplane> for Plane {
+    type Output = Line;
+
+    fn meet(self, hyperplane: Hyperplane) -> Line {
+        hyperplane.meet(self)
+    }
+}
+
+impl Mul<Line> for Transform {
+    type Output = Line;
+
+    fn mul(self, line: Line) -> Line {
+        Line::from_point_direction(self * line.project(Point4::ORIGIN), self * line.direction())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f32 = 1e-4;
+
+    fn lines() -> [(Point4, Vec4); 3] {
+        [
+            (Point4::ORIGIN, Vec4::X),
+            (
+                Point4::new(0.0, 0.0, 2.0, -1.0),
+                Vec4::new(0.0, 3.0, 0.0, 0.0),
+            ),
+            (
+                Point4::new(1.0, -2.0, 3.5, 0.25),
+                Vec4::new(0.3, -1.2, 2.0, 0.7),
+            ),
+        ]
+    }
+
+    fn points() -> [Point4; 3] {
+        [
+            Point4::ORIGIN,
+            Point4::new(-4.0, 0.5, 2.0, 7.0),
+            Point4::new(0.1, 0.2, -0.3, -6.0),
+        ]
+    }
+
+    #[test]
+    fn project_matches_projecting_onto_the_direction() {
+        for (origin, direction) in lines() {
+            let line = Line::from_point_direction(origin, direction);
+            let unit = direction.normalised();
+            for point in points() {
+                let expected = origin + unit * (point - origin).dot(unit);
+                let projected = line.project(point);
+                assert!(
+                    projected.abs_diff_eq(expected, TOLERANCE),
+                    "{line:?} {point:?}"
+                );
+                assert!(line.project(projected).abs_diff_eq(projected, TOLERANCE));
+                assert!((line.distance(point) - point.distance(expected)).abs() < TOLERANCE);
+                // the perpendicular hyperplane crosses the line at the projection
+                let perpendicular = line.perpendicular_hyperplane(point);
+                assert!(perpendicular.signed_distance(point).abs() < TOLERANCE);
+                assert!(perpendicular.signed_distance(projected).abs() < TOLERANCE);
+            }
+            assert!(line.normalised().direction().abs_diff_eq(unit, TOLERANCE));
+        }
+    }
+
+    #[test]
+    fn join_then_meet_gives_back_the_incident_flats() {
+        let a = Point4::new(1.0, -2.0, 3.5, 0.25);
+        let b = Point4::new(-4.0, 0.5, 2.0, 7.0);
+        let line = a.join(b);
+        assert!(line.project(a).abs_diff_eq(a, TOLERANCE));
+        assert!(line.project(b).abs_diff_eq(b, TOLERANCE));
+        assert!(line.direction().abs_diff_eq(b - a, TOLERANCE));
+
+        // a plane and a hyperplane that both contain the line cross along it
+        let plane = Plane::from_point_direction(a, (b - a).wedge(Vec4::Y));
+        let hyperplane = line.join(Line::from_point_direction(a + Vec4::Z, Vec4::W));
+        let crossing = hyperplane.meet(plane);
+        for point in [a, b, a.lerp(b, 3.0)] {
+            assert!(
+                crossing.project(point).abs_diff_eq(point, TOLERANCE),
+                "{point:?}"
+            );
+        }
+        assert!(
+            crossing
+                .angle(line)
+                .min(std::f32::consts::PI - crossing.angle(line))
+                < 1e-3
+        );
+    }
+
+    #[test]
+    fn angle_between_directions() {
+        let a = Line::from_point_direction(Point4::ORIGIN, Vec4::X);
+        let b = Line::from_point_direction(
+            Point4::new(1.0, 2.0, 3.0, 4.0),
+            Vec4::new(2.0, 0.0, 2.0, 0.0),
+        );
+        assert!((a.angle(b) - std::f32::consts::FRAC_PI_4).abs() < TOLERANCE);
+    }
+}
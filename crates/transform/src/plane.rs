@@ -0,0 +1,319 @@
+use crate::{Bivector, Hyperplane, Join, Line, Meet, Point4, Transform, point::plane_plane_meet};
+use bevy::reflect::{Reflect, prelude::ReflectDefault};
+use bytemuck::{Pod, Zeroable};
+use std::ops::Mul;
+
+/// A 2D flat in 4D space, stored as a bivector of 4D PGA
+#[derive(Reflect, Debug, Default, Clone, Copy, Zeroable, Pod)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[repr(C)]
+pub struct Plane {
+    pub e01: f32,
+    pub e02: f32,
+    pub e03: f32,
+    pub e04: f32,
+    pub e12: f32,
+    pub e13: f32,
+    pub e14: f32,
+    pub e23: f32,
+    pub e24: f32,
+    pub e34: f32,
+}
+
+impl Plane {
+    /// The plane through `point` spanned by `direction`, such as `u.wedge(v)` for two directions in the plane
+    #[must_use]
+    pub const fn from_point_direction(point: Point4, direction: Bivector) -> Self {
+        let Point4 { x, y, z, w } = point;
+        let Bivector {
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+        } = direction;
+        Self {
+            e01: -e23 * w - e24 * z - e34 * y,
+            e02: e13 * w + e14 * z - e34 * x,
+            e03: e14 * y + e24 * x - e12 * w,
+            e04: -e12 * z - e13 * y - e23 * x,
+            e12: -e34,
+            e13: e24,
+            e14: -e23,
+            e23: -e14,
+            e24: e13,
+            e34: -e12,
+        }
+    }
+
+    #[must_use]
+    pub const fn direction(self) -> Bivector {
+        Bivector {
+            e12: -self.e34,
+            e13: self.e24,
+            e14: -self.e23,
+            e23: -self.e14,
+            e24: self.e13,
+            e34: -self.e12,
+        }
+    }
+
+    /// Scales the plane so its direction has a magnitude of 1
+    #[must_use]
+    pub fn normalised(self) -> Self {
+        let inverse_magnitude = self.direction().magnitude().recip();
+        let Self {
+            e01,
+            e02,
+            e03,
+            e04,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+        } = self;
+        Self {
+            e01: e01 * inverse_magnitude,
+            e02: e02 * inverse_magnitude,
+            e03: e03 * inverse_magnitude,
+            e04: e04 * inverse_magnitude,
+            e12: e12 * inverse_magnitude,
+            e13: e13 * inverse_magnitude,
+            e14: e14 * inverse_magnitude,
+            e23: e23 * inverse_magnitude,
+            e24: e24 * inverse_magnitude,
+            e34: e34 * inverse_magnitude,
+        }
+    }
+
+    /// NaN if the direction is zero, like [`Self::project`]
+    #[must_use]
+    pub fn distance(self, point: Point4) -> f32 {
+        point.distance(self.project(point))
+    }
+
+    /// The plane through `point` that is completely perpendicular to this plane, crossing it at a single point
+    #[must_use]
+    pub const fn perpendicular_plane(self, point: Point4) -> Self {
+        // the euclidean part of a plane stores the directions perpendicular to it
+        let Self {
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            ..
+        } = self;
+        Self::from_point_direction(
+            point,
+            Bivector {
+                e12,
+                e13,
+                e14,
+                e23,
+                e24,
+                e34,
+            },
+        )
+    }
+
+    /// The closest point on the plane, a plane with a zero direction has no points so this gives NaN
+    #[must_use]
+    pub const fn project(self, point: Point4) -> Point4 {
+        Point4::from_homogeneous(plane_plane_meet(self.perpendicular_plane(point), self))
+    }
+}
+
+/// The plane where the two hyperplanes cross
+impl Meet for Hyperplane {
+    type Output = Plane;
+
+    fn meet(self, other: Self) -> Plane {
+        let Self {
+            e0: a0,
+            e1: a1,
+            e2: a2,
+            e3: a3,
+            e4: a4,
+        } = self;
+        let Self {
+            e0: b0,
+            e1: b1,
+            e2: b2,
+            e3: b3,
+            e4: b4,
+        } = other;
+        Plane {
+            e01: a0 * b1 - a1 * b0,
+            e02: a0 * b2 - a2 * b0,
+            e03: a0 * b3 - a3 * b0,
+            e04: a0 * b4 - a4 * b0,
+            e12: a1 * b2 - a2 * b1,
+            e13: a1 * b3 - a3 * b1,
+            e14: a1 * b4 - a4 * b1,
+            e23: a2 * b3 - a3 * b2,
+            e24: a2 * b4 - a4 * b2,
+            e34: a3 * b4 - a4 * b3,
+        }
+    }
+}
+
+/// The plane containing the point and the line
+impl Join<Line> for Point4 {
+    type Output = Plane;
+
+    fn join(self, line: Line) -> Plane {
+        let Point4 { x, y, z, w } = self;
+        let Line {
+            e012: b012,
+            e013: b013,
+            e014: b014,
+            e023: b023,
+            e024: b024,
+            e034: b034,
+            e123: b123,
+            e124: b124,
+            e134: b134,
+            e234: b234,
+        } = line;
+        Plane {
+            e01: b012 * y + b014 * w - b013 * z,
+            e02: b012 * x + b024 * w - b023 * z,
+            e03: b013 * x + b034 * w - b023 * y,
+            e04: b014 * x + b034 * z - b024 * y,
+            e12: b012 + b124 * w - b123 * z,
+            e13: b013 + b134 * w - b123 * y,
+            e14: b014 + b134 * z - b124 * y,
+            e23: b023 + b234 * w - b123 * x,
+            e24: b024 + b234 * z - b124 * x,
+            e34: b034 + b234 * y - b134 * x,
+        }
+    }
+}
+
+impl Join<Point4> for Line {
+    type Output = Plane;
+
+    fn join(self, point: Point4) -> Plane {
+        point.join(self)
+    }
+}
+
+impl Mul<Plane> for Transform {
+    type Output = Plane;
+
+    fn mul(self, plane: Plane) -> Plane {
+        Plane::from_point_direction(
+            self * plane.project(Point4::ORIGIN),
+            self.rotor_part().rotate_bivector(plane.direction()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vec4;
+
+    const TOLERANCE: f32 = 1e-4;
+
+    /// A point on the plane and two orthonormal directions spanning it
+    fn planes() -> [(Point4, Vec4, Vec4); 3] {
+        let skewed = Vec4::new(0.3, -1.2, 2.0, 0.7).normalised();
+        let other = Vec4::new(1.0, 0.5, 0.0, 0.25);
+        let other = (other - skewed * other.dot(skewed)).normalised();
+        [
+            (Point4::ORIGIN, Vec4::X, Vec4::Y),
+            (Point4::new(0.0, 0.0, 2.0, -1.0), Vec4::Y, Vec4::W),
+            (Point4::new(1.0, -2.0, 3.5, 0.25), skewed, other),
+        ]
+    }
+
+    fn points() -> [Point4; 3] {
+        [
+            Point4::ORIGIN,
+            Point4::new(-4.0, 0.5, 2.0, 7.0),
+            Point4::new(0.1, 0.2, -0.3, -6.0),
+        ]
+    }
+
+    #[test]
+    fn project_matches_projecting_onto_the_directions() {
+        for (origin, u, v) in planes() {
+            let plane = Plane::from_point_direction(origin, u.wedge(v));
+            for point in points() {
+                let offset = point - origin;
+                let expected = origin + u * offset.dot(u) + v * offset.dot(v);
+                let projected = plane.project(point);
+                assert!(
+                    projected.abs_diff_eq(expected, TOLERANCE),
+                    "{plane:?} {point:?}"
+                );
+                assert!(plane.project(projected).abs_diff_eq(projected, TOLERANCE));
+                assert!((plane.distance(point) - point.distance(expected)).abs() < TOLERANCE);
+            }
+            // scaling the direction doesnt move the plane
+            let scaled = Plane::from_point_direction(origin, u.wedge(v).scaled(3.0));
+            assert!(
+                scaled
+                    .project(points()[1])
+                    .abs_diff_eq(plane.project(points()[1]), TOLERANCE)
+            );
+            assert!(
+                scaled
+                    .normalised()
+                    .direction()
+                    .abs_diff_eq(u.wedge(v), TOLERANCE)
+            );
+        }
+    }
+
+    #[test]
+    fn perpendicular_plane_crosses_at_the_projection() {
+        for (origin, u, v) in planes() {
+            let plane = Plane::from_point_direction(origin, u.wedge(v));
+            for point in points() {
+                let perpendicular = plane.perpendicular_plane(point);
+                assert!(perpendicular.project(point).abs_diff_eq(point, TOLERANCE));
+                let crossing = perpendicular.meet(plane).unwrap();
+                assert!(crossing.abs_diff_eq(plane.project(point), TOLERANCE));
+            }
+        }
+    }
+
+    #[test]
+    fn join_then_meet_gives_back_the_incident_flats() {
+        let a = Point4::new(1.0, -2.0, 3.5, 0.25);
+        let b = Point4::new(-4.0, 0.5, 2.0, 7.0);
+        let c = Point4::new(0.1, 0.2, -0.3, -6.0);
+        let plane = a.join(b.join(c));
+        for point in [a, b, c] {
+            assert!(
+                plane.project(point).abs_diff_eq(point, TOLERANCE),
+                "{point:?}"
+            );
+        }
+        assert!(
+            b.join(c)
+                .join(a)
+                .direction()
+                .abs_diff_eq(plane.direction(), TOLERANCE)
+        );
+
+        // two hyperplanes through all three points cross in the same plane
+        let first = plane.join(Point4::new(3.0, 3.0, 3.0, 3.0));
+        let second = plane.join(Point4::new(-1.0, 4.0, 0.0, 2.0));
+        let crossing = first.meet(second);
+        for point in [a, b, c] {
+            assert!(
+                crossing.project(point).abs_diff_eq(point, TOLERANCE),
+                "{point:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use crate::{DPoint4, Hyperplane, Line, Meet, Plane, Vec4};
+use bevy::reflect::{Reflect, prelude::ReflectDefault};
+use bytemuck::{Pod, Zeroable};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// A position in 4D space, unlike a [`Vec4`] it is affected by the translation of a [`Transform`](crate::Transform)
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Zeroable, Pod)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[repr(C)]
+pub struct Point4 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Point4 {
+    pub const ORIGIN: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    #[must_use]
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// The point at the end of `offset` from the origin
+    #[must_use]
+    pub const fn from_vector(offset: Vec4) -> Self {
+        let Vec4 { x, y, z, w } = offset;
+        Self::new(x, y, z, w)
+    }
+
+    /// The offset of this point from the origin
+    #[must_use]
+    pub const fn to_vector(self) -> Vec4 {
+        let Self { x, y, z, w } = self;
+        Vec4::new(x, y, z, w)
+    }
+
+    /// From the weights of a point in 4D PGA, `e1234` being zero means the point is at infinity
+    #[must_use]
+    pub(crate) const fn from_homogeneous(weights: [f32; 5]) -> Self {
+        let [e0123, e0124, e0134, e0234, e1234] = weights;
+        Self::new(e0234 / e1234, e0134 / e1234, e0124 / e1234, e0123 / e1234)
+    }
+
+    #[must_use]
+    const fn try_from_homogeneous(weights: [f32; 5]) -> Option<Self> {
+        if weights[4] != 0.0 {
+            Some(Self::from_homogeneous(weights))
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub const fn distance_squared(self, other: Self) -> f32 {
+        let Self { x, y, z, w } = self;
+        let (x, y, z, w) = (x - other.x, y - other.y, z - other.z, w - other.w);
+        x * x + y * y + z * z + w * w
+    }
+
+    #[must_use]
+    pub fn distance(self, other: Self) -> f32 {
+        self.distance_squared(other).sqrt()
+    }
+
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+
+    #[must_use]
+    pub const fn to_array(self) -> [f32; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Whether every component is within `max_abs_diff` of the matching component of `other`
+    #[must_use]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f32) -> bool {
+        let (a, b) = (self.to_array(), other.to_array());
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() <= max_abs_diff)
+    }
+
+    #[must_use]
+    pub const fn as_dpoint4(self) -> DPoint4 {
+        DPoint4::new(self.x as f64, self.y as f64, self.z as f64, self.w as f64)
+    }
+}
+
+impl Add<Vec4> for Point4 {
+    type Output = Self;
+
+    fn add(self, offset: Vec4) -> Self {
+        Self::from_vector(self.to_vector() + offset)
+    }
+}
+
+impl AddAssign<Vec4> for Point4 {
+    fn add_assign(&mut self, offset: Vec4) {
+        *self = *self + offset;
+    }
+}
+
+impl Sub<Vec4> for Point4 {
+    type Output = Self;
+
+    fn sub(self, offset: Vec4) -> Self {
+        Self::from_vector(self.to_vector() - offset)
+    }
+}
+
+impl SubAssign<Vec4> for Point4 {
+    fn sub_assign(&mut self, offset: Vec4) {
+        *self = *self - offset;
+    }
+}
+
+impl Sub for Point4 {
+    type Output = Vec4;
+
+    fn sub(self, other: Self) -> Vec4 {
+        self.to_vector() - other.to_vector()
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Point4 {
+    fn from((x, y, z, w): (f32, f32, f32, f32)) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+impl From<Point4> for (f32, f32, f32, f32) {
+    fn from(Point4 { x, y, z, w }: Point4) -> Self {
+        (x, y, z, w)
+    }
+}
+
+impl From<[f32; 4]> for Point4 {
+    fn from([x, y, z, w]: [f32; 4]) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+impl From<Point4> for [f32; 4] {
+    fn from(point: Point4) -> Self {
+        point.to_array()
+    }
+}
+
+/// The point where the hyperplane crosses the line, or [`None`] if the line is parallel to it
+impl Meet<Line> for Hyperplane {
+    type Output = Option<Point4>;
+
+    fn meet(self, line: Line) -> Option<Point4> {
+        Point4::try_from_homogeneous(hyperplane_line_meet(self, line))
+    }
+}
+
+impl Meet<Hyperplane> for Line {
+    type Output = Option<Point4>;
+
+    fn meet(self, hyperplane: Hyperplane) -> Option<Point4> {
+        hyperplane.meet(self)
+    }
+}
+
+/// The point where the two planes cross, or [`None`] if they share a direction
+impl Meet for Plane {
+    type Output = Option<Point4>;
+
+    fn meet(self, other: Self) -> Option<Point4> {
+        Point4::try_from_homogeneous(plane_plane_meet(self, other))
+    }
+}
+
+pub(crate) const fn hyperplane_line_meet(hyperplane: Hyperplane, line: Line) -> [f32; 5] {
+    let Hyperplane {
+        e0: a0,
+        e1: a1,
+        e2: a2,
+        e3: a3,
+        e4: a4,
+    } = hyperplane;
+    let Line {
+        e012: b012,
+        e013: b013,
+        e014: b014,
+        e023: b023,
+        e024: b024,
+        e034: b034,
+        e123: b123,
+        e124: b124,
+        e134: b134,
+        e234: b234,
+    } = line;
+    [
+        a0 * b123 + a2 * b013 - a1 * b023 - a3 * b012,
+        a0 * b124 + a2 * b014 - a1 * b024 - a4 * b012,
+        a0 * b134 + a3 * b014 - a1 * b034 - a4 * b013,
+        a0 * b234 + a3 * b024 - a2 * b034 - a4 * b023,
+        a1 * b234 + a3 * b124 - a2 * b134 - a4 * b123,
+    ]
+}
+
+pub(crate) const fn plane_plane_meet(a: Plane, b: Plane) -> [f32; 5] {
+    let Plane {
+        e01: a01,
+        e02: a02,
+        e03: a03,
+        e04: a04,
+        e12: a12,
+        e13: a13,
+        e14: a14,
+        e23: a23,
+        e24: a24,
+        e34: a34,
+    } = a;
+    let Plane {
+        e01: b01,
+        e02: b02,
+        e03: b03,
+        e04: b04,
+        e12: b12,
+        e13: b13,
+        e14: b14,
+        e23: b23,
+        e24: b24,
+        e34: b34,
+    } = b;
+    [
+        a01 * b23 + a03 * b12 + a12 * b03 + a23 * b01 - a02 * b13 - a13 * b02,
+        a01 * b24 + a04 * b12 + a12 * b04 + a24 * b01 - a02 * b14 - a14 * b02,
+        a01 * b34 + a04 * b13 + a13 * b04 + a34 * b01 - a03 * b14 - a14 * b03,
+        a02 * b34 + a04 * b23 + a23 * b04 + a34 * b02 - a03 * b24 - a24 * b03,
+        a12 * b34 + a14 * b23 + a23 * b14 + a34 * b12 - a13 * b24 - a24 * b13,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOLERANCE: f32 = 1e-5;
+
+    #[test]
+    fn points_and_offsets() {
+        let a = Point4::new(1.0, -2.0, 3.5, 0.25);
+        let b = Point4::new(-4.0, 0.5, 2.0, 7.0);
+        let offset = b - a;
+        assert!((a + offset).abs_diff_eq(b, TOLERANCE));
+        assert!((b - offset).abs_diff_eq(a, TOLERANCE));
+        assert!((a.distance(b) - offset.length()).abs() < TOLERANCE);
+        assert!(a.lerp(b, 0.5).abs_diff_eq(a + offset * 0.5, TOLERANCE));
+        assert_eq!(Point4::from_vector(a.to_vector()), a);
+        assert_eq!(Point4::from(<[f32; 4]>::from(a)), a);
+        assert_eq!(Point4::from(<(f32, f32, f32, f32)>::from(a)), a);
+    }
+
+    #[test]
+    fn meets_give_back_the_shared_point() {
+        let point = Point4::new(1.0, -2.0, 3.5, 0.25);
+        let hyperplane = Hyperplane::from_point_normal(point, Vec4::new(0.3, -1.2, 2.0, 0.7));
+        let line = Line::from_point_direction(point, Vec4::new(-1.0, 0.5, 0.0, 4.0));
+        assert!(hyperplane.meet(line).unwrap().abs_diff_eq(point, 1e-4));
+        assert!(line.meet(hyperplane).unwrap().abs_diff_eq(point, 1e-4));
+        // a line along the hyperplane never crosses it
+        let along = Line::from_point_direction(point + Vec4::X, Vec4::new(1.2, 0.3, 0.0, 0.0));
+        assert!(hyperplane.meet(along).is_none());
+
+        let first = Plane::from_point_direction(point, Vec4::X.wedge(Vec4::Y));
+        let second =
+            Plane::from_point_direction(point, Vec4::new(0.0, 1.0, 1.0, 0.0).wedge(Vec4::W));
+        assert!(first.meet(second).unwrap().abs_diff_eq(point, 1e-4));
+        // parallel planes share a direction
+        let parallel = Plane::from_point_direction(point + Vec4::Z, Vec4::X.wedge(Vec4::Y));
+        assert!(first.meet(parallel).is_none());
+    }
+}
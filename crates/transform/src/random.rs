@@ -0,0 +1,140 @@
+use crate::{Point4, Rotor, Transform, Vec4};
+use bevy::math::Quat;
+use rand::{
+    Rng,
+    distributions::{Distribution, Standard},
+};
+use std::f32::consts::TAU;
+
+/// A uniformly distributed unit quaternion, from Shoemake's method
+fn random_quaternion<R: Rng + ?Sized>(rng: &mut R) -> Quat {
+    let (u1, u2, u3) = (rng.r#gen::<f32>(), rng.r#gen::<f32>(), rng.r#gen::<f32>());
+    let (a, b) = ((1.0 - u1).sqrt(), u1.sqrt());
+    let ((sin2, cos2), (sin3, cos3)) = ((TAU * u2).sin_cos(), (TAU * u3).sin_cos());
+    Quat::from_xyzw(a * sin2, a * cos2, b * sin3, b * cos3)
+}
+
+impl Rotor {
+    /// A rotation drawn uniformly from all rotations in 4D, that is from the Haar measure of SO(4),
+    /// unlike composing `rotation_*` rotations with random angles, which favours some orientations
+    #[must_use]
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        // every rotation is `left * direction * right.conjugate()` for two unit quaternions,
+        // and picking them independently and uniformly picks the rotation uniformly
+        Self::from_quaternion_pair(random_quaternion(rng), random_quaternion(rng))
+    }
+}
+
+impl Vec4 {
+    /// A unit vector drawn uniformly from the 3-sphere
+    #[must_use]
+    pub fn random_direction<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        bevy::math::Vec4::from(random_quaternion(rng)).into()
+    }
+}
+
+impl Point4 {
+    /// A point drawn uniformly from the solid 4-ball
+    #[must_use]
+    pub fn random_in_ball<R: Rng + ?Sized>(rng: &mut R, center: Self, radius: f32) -> Self {
+        // the volume within a distance grows with its fourth power
+        let distance = radius * rng.r#gen::<f32>().sqrt().sqrt();
+        center + Vec4::random_direction(rng) * distance
+    }
+
+    /// A point drawn uniformly from the axis aligned box between `min` and `max`
+    #[must_use]
+    pub fn random_in_box<R: Rng + ?Sized>(rng: &mut R, min: Self, max: Self) -> Self {
+        Self::new(
+            min.x + (max.x - min.x) * rng.r#gen::<f32>(),
+            min.y + (max.y - min.y) * rng.r#gen::<f32>(),
+            min.z + (max.z - min.z) * rng.r#gen::<f32>(),
+            min.w + (max.w - min.w) * rng.r#gen::<f32>(),
+        )
+    }
+}
+
+impl Transform {
+    /// A uniformly random orientation at `position`, which can come from [`Point4::random_in_ball`] or [`Point4::random_in_box`]
+    #[must_use]
+    pub fn random_at<R: Rng + ?Sized>(rng: &mut R, position: Point4) -> Self {
+        Self::from_translation_rotor(position.to_vector(), Rotor::random(rng))
+    }
+}
+
+/// Sampling a rotor from [`Standard`] is the same as [`Rotor::random`]
+impl Distribution<Rotor> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rotor {
+        Rotor::random(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{SeedableRng, rngs::SmallRng};
+
+    const SAMPLES: usize = 10_000;
+
+    #[test]
+    fn random_rotors_are_normalised() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        for _ in 0..SAMPLES {
+            let rotor: Rotor = rng.r#gen();
+            assert!((rotor.magnitude() - 1.0).abs() < 1e-5, "{rotor:?}");
+            // a product of two unit quaternions, so it has no drift to remove either
+            assert!(rotor.normalized().abs_diff_eq(rotor, 1e-5), "{rotor:?}");
+        }
+    }
+
+    #[test]
+    fn random_rotations_have_no_preferred_direction() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let mut sums = [Vec4::ZERO; 4];
+        for _ in 0..SAMPLES {
+            let rotor = Rotor::random(&mut rng);
+            for (sum, axis) in sums.iter_mut().zip([Vec4::X, Vec4::Y, Vec4::Z, Vec4::W]) {
+                *sum += rotor.rotate(axis);
+            }
+        }
+        // each component has a standard deviation of a half, so the mean of 10000 is well within this
+        for sum in sums {
+            let mean = sum / SAMPLES as f32;
+            assert!(mean.length() < 0.03, "{mean:?}");
+        }
+    }
+
+    #[test]
+    fn random_directions_and_points() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let mut sum = Vec4::ZERO;
+        for _ in 0..SAMPLES {
+            let direction = Vec4::random_direction(&mut rng);
+            assert!((direction.length() - 1.0).abs() < 1e-5);
+            sum += direction;
+        }
+        assert!((sum / SAMPLES as f32).length() < 0.03);
+
+        let center = Point4::new(1.0, -2.0, 3.0, 0.5);
+        let min = Point4::new(-1.0, 0.0, 2.0, -3.0);
+        let max = Point4::new(1.0, 0.5, 4.0, 3.0);
+        for _ in 0..SAMPLES {
+            assert!(Point4::random_in_ball(&mut rng, center, 2.0).distance(center) <= 2.0 + 1e-5);
+            let point = Point4::random_in_box(&mut rng, min, max);
+            assert!(
+                point
+                    .to_array()
+                    .into_iter()
+                    .zip(min.to_array())
+                    .all(|(p, min)| p >= min)
+            );
+            assert!(
+                point
+                    .to_array()
+                    .into_iter()
+                    .zip(max.to_array())
+                    .all(|(p, max)| p <= max)
+            );
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Forms of [`Transform`] and [`Rotor`] for files people write by hand,
+//! use `#[serde(with = "transform::readable::transform")]` on a field to store it this way
+
+use crate::{Bivector, Rotor, Transform, Vec4};
+use serde::{Deserialize, Serialize};
+
+/// A rotation as the angle it turns within each coordinate plane, the planes all rotate at the same time
+/// rather than one after another, which only makes a difference for planes that share an axis
+///
+/// Planes that arent rotated are left out when serializing and can be left out when deserializing
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct PlaneAngles {
+    #[serde(skip_serializing_if = "is_zero")]
+    pub xy: f32,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub xz: f32,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub xw: f32,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub yz: f32,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub yw: f32,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub zw: f32,
+}
+
+fn is_zero(angle: &f32) -> bool {
+    *angle == 0.0
+}
+
+impl From<Bivector> for PlaneAngles {
+    fn from(bivector: Bivector) -> Self {
+        // the inverse of the `Bivector::rotation_*` constructors
+        Self {
+            xy: bivector.e12 * 2.0,
+            xz: bivector.e13 * -2.0,
+            xw: bivector.e14 * 2.0,
+            yz: bivector.e23 * 2.0,
+            yw: bivector.e24 * -2.0,
+            zw: bivector.e34 * 2.0,
+        }
+    }
+}
+
+impl From<PlaneAngles> for Bivector {
+    fn from(angles: PlaneAngles) -> Self {
+        let PlaneAngles {
+            xy,
+            xz,
+            xw,
+            yz,
+            yw,
+            zw,
+        } = angles;
+        Self {
+            e12: Self::rotation_xy(xy).e12,
+            e13: Self::rotation_xz(xz).e13,
+            e14: Self::rotation_xw(xw).e14,
+            e23: Self::rotation_yz(yz).e23,
+            e24: Self::rotation_yw(yw).e24,
+            e34: Self::rotation_zw(zw).e34,
+        }
+    }
+}
+
+impl From<Rotor> for PlaneAngles {
+    fn from(rotor: Rotor) -> Self {
+        rotor.log().into()
+    }
+}
+
+impl From<PlaneAngles> for Rotor {
+    fn from(angles: PlaneAngles) -> Self {
+        Self::exp(angles.into())
+    }
+}
+
+/// The rotation is applied first, so the translation is where the origin ends up
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct ReadableTransform {
+    pub translation: Vec4,
+    pub rotation: PlaneAngles,
+}
+
+impl From<Transform> for ReadableTransform {
+    fn from(transform: Transform) -> Self {
+        let (translation, rotor) = transform.to_translation_rotor();
+        Self {
+            translation,
+            rotation: rotor.into(),
+        }
+    }
+}
+
+impl From<ReadableTransform> for Transform {
+    fn from(readable: ReadableTransform) -> Self {
+        Self::from_translation_rotor(readable.translation, readable.rotation.into())
+    }
+}
+
+/// Stores a [`Rotor`] as [`PlaneAngles`]
+pub mod rotor {
+    use super::PlaneAngles;
+    use crate::Rotor;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(rotor: &Rotor, serializer: S) -> Result<S::Ok, S::Error> {
+        PlaneAngles::from(*rotor).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rotor, D::Error> {
+        PlaneAngles::deserialize(deserializer).map(Rotor::from)
+    }
+}
+
+/// Stores a [`Transform`] as a [`ReadableTransform`]
+pub mod transform {
+    use super::ReadableTransform;
+    use crate::Transform;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        transform: &Transform,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        ReadableTransform::from(*transform).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Transform, D::Error> {
+        ReadableTransform::deserialize(deserializer).map(Transform::from)
+    }
+}
@@ -0,0 +1,364 @@
+use crate::{GlobalTransform, Hyperplane, Point4, Transform, Vec4};
+use bevy::{
+    ecs::component::Component,
+    reflect::{Reflect, prelude::ReflectDefault},
+};
+use bytemuck::{Pod, Zeroable};
+use std::ops::{Mul, MulAssign, Neg};
+
+/// An odd versor of 4D PGA, a reflection in a hyperplane combined with any rigid motion,
+/// on an entity it mirrors the entity before its [`Transform`] is applied
+#[derive(Component, Reflect, Debug, Clone, Copy, Zeroable, Pod)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[require(GlobalTransform)]
+#[repr(C)]
+pub struct Reflection {
+    pub e0: f32,
+    pub e1: f32,
+    pub e2: f32,
+    pub e3: f32,
+    pub e4: f32,
+    pub e012: f32,
+    pub e013: f32,
+    pub e014: f32,
+    pub e023: f32,
+    pub e024: f32,
+    pub e034: f32,
+    pub e123: f32,
+    pub e124: f32,
+    pub e134: f32,
+    pub e234: f32,
+    pub e01234: f32,
+}
+
+impl Reflection {
+    /// Reflects in the hyperplane `x = 0`
+    pub const MIRROR_X: Self = Self::from_transform(Transform::IDENTITY);
+
+    /// Reflects in `hyperplane`
+    #[must_use]
+    pub fn new(hyperplane: Hyperplane) -> Self {
+        let Hyperplane { e0, e1, e2, e3, e4 } = hyperplane.normalised();
+        Self {
+            e0,
+            e1,
+            e2,
+            e3,
+            e4,
+            ..Self::ZERO
+        }
+    }
+
+    const ZERO: Self = Self {
+        e0: 0.0,
+        e1: 0.0,
+        e2: 0.0,
+        e3: 0.0,
+        e4: 0.0,
+        e012: 0.0,
+        e013: 0.0,
+        e014: 0.0,
+        e023: 0.0,
+        e024: 0.0,
+        e034: 0.0,
+        e123: 0.0,
+        e124: 0.0,
+        e134: 0.0,
+        e234: 0.0,
+        e01234: 0.0,
+    };
+
+    /// Reflects in the hyperplane `x = 0` and then applies `transform`
+    #[must_use]
+    pub const fn from_transform(transform: Transform) -> Self {
+        let Transform {
+            s,
+            e01,
+            e02,
+            e03,
+            e04,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            e0123,
+            e0124,
+            e0134,
+            e0234,
+            e1234,
+        } = transform;
+        Self {
+            e0: e01,
+            e1: s,
+            e2: -e12,
+            e3: -e13,
+            e4: -e14,
+            e012: -e02,
+            e013: -e03,
+            e014: -e04,
+            e023: e0123,
+            e024: e0124,
+            e034: e0134,
+            e123: e23,
+            e124: e24,
+            e134: e34,
+            e234: -e1234,
+            e01234: -e0234,
+        }
+    }
+
+    /// The transform left after undoing a reflection in the hyperplane `x = 0`, the inverse of [`Self::from_transform`]
+    #[must_use]
+    pub const fn to_transform(self) -> Transform {
+        let Self {
+            e0,
+            e1,
+            e2,
+            e3,
+            e4,
+            e012,
+            e013,
+            e014,
+            e023,
+            e024,
+            e034,
+            e123,
+            e124,
+            e134,
+            e234,
+            e01234,
+        } = self;
+        Transform {
+            s: e1,
+            e01: e0,
+            e02: -e012,
+            e03: -e013,
+            e04: -e014,
+            e12: -e2,
+            e13: -e3,
+            e14: -e4,
+            e23: e123,
+            e24: e124,
+            e34: e134,
+            e0123: e023,
+            e0124: e024,
+            e0134: e034,
+            e0234: -e01234,
+            e1234: -e234,
+        }
+    }
+
+    #[must_use]
+    pub const fn magnitude_squared(self) -> f32 {
+        self.to_transform().magnitude_squared()
+    }
+
+    #[must_use]
+    pub fn magnitude(self) -> f32 {
+        self.to_transform().magnitude()
+    }
+
+    #[must_use]
+    pub fn normalised(self) -> Self {
+        Self::from_transform(self.to_transform().normalised())
+    }
+
+    #[must_use]
+    pub const fn inverse(self) -> Self {
+        let Self {
+            e0,
+            e1,
+            e2,
+            e3,
+            e4,
+            e012,
+            e013,
+            e014,
+            e023,
+            e024,
+            e034,
+            e123,
+            e124,
+            e134,
+            e234,
+            e01234,
+        } = self;
+        Self {
+            e0,
+            e1,
+            e2,
+            e3,
+            e4,
+            e012: -e012,
+            e013: -e013,
+            e014: -e014,
+            e023: -e023,
+            e024: -e024,
+            e034: -e034,
+            e123: -e123,
+            e124: -e124,
+            e134: -e134,
+            e234: -e234,
+            e01234,
+        }
+    }
+
+    /// Whether every component is within `max_abs_diff` of the matching component of `other`
+    #[must_use]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f32) -> bool {
+        self.to_transform()
+            .abs_diff_eq(other.to_transform(), max_abs_diff)
+    }
+
+    /// Like [`Self::abs_diff_eq`] but also true when `other` is close to `-self`, which is the same reflection
+    #[must_use]
+    pub fn approx_eq(self, other: Self, max_abs_diff: f32) -> bool {
+        self.to_transform()
+            .approx_eq(other.to_transform(), max_abs_diff)
+    }
+
+    #[must_use]
+    pub fn then(self, other: Transform) -> Self {
+        // moving the x mirror to the end reflects `other` in it
+        Self::from_transform(self.to_transform().then(mirror_x(other)))
+    }
+
+    /// Two reflections combine into a rigid motion
+    #[must_use]
+    pub fn then_reflection(self, other: Self) -> Transform {
+        self.to_transform().then(mirror_x(other.to_transform()))
+    }
+
+    #[must_use]
+    pub fn transform(self, point: Point4) -> Point4 {
+        let Point4 { x, y, z, w } = point;
+        self.to_transform().transform(Point4::new(-x, y, z, w))
+    }
+
+    pub fn transform_points(self, points: &mut [Point4]) {
+        for point in points.iter_mut() {
+            point.x = -point.x;
+        }
+        self.to_transform().transform_points(points);
+    }
+
+    /// Directions are only rotated and mirrored, the translation doesnt affect them
+    #[must_use]
+    pub const fn transform_direction(self, direction: Vec4) -> Vec4 {
+        let Vec4 { x, y, z, w } = direction;
+        self.to_transform()
+            .rotor_part()
+            .rotate(Vec4::new(-x, y, z, w))
+    }
+}
+
+/// Reflects a transform in the hyperplane `x = 0`, the same as `MIRROR_X * transform * MIRROR_X`
+const fn mirror_x(transform: Transform) -> Transform {
+    let Transform {
+        s,
+        e01,
+        e02,
+        e03,
+        e04,
+        e12,
+        e13,
+        e14,
+        e23,
+        e24,
+        e34,
+        e0123,
+        e0124,
+        e0134,
+        e0234,
+        e1234,
+    } = transform;
+    Transform {
+        s,
+        e01: -e01,
+        e02,
+        e03,
+        e04,
+        e12: -e12,
+        e13: -e13,
+        e14: -e14,
+        e23,
+        e24,
+        e34,
+        e0123: -e0123,
+        e0124: -e0124,
+        e0134: -e0134,
+        e0234,
+        e1234: -e1234,
+    }
+}
+
+impl Default for Reflection {
+    fn default() -> Self {
+        Self::MIRROR_X
+    }
+}
+
+impl From<Hyperplane> for Reflection {
+    fn from(hyperplane: Hyperplane) -> Self {
+        Self::new(hyperplane)
+    }
+}
+
+/// `a * b` is `a.then(b)`, so `(a * b) * point == a * (b * point)`
+impl Mul<Transform> for Reflection {
+    type Output = Self;
+
+    fn mul(self, other: Transform) -> Self {
+        self.then(other)
+    }
+}
+
+impl MulAssign<Transform> for Reflection {
+    fn mul_assign(&mut self, other: Transform) {
+        *self = self.then(other);
+    }
+}
+
+impl Mul<Reflection> for Transform {
+    type Output = Reflection;
+
+    fn mul(self, other: Reflection) -> Reflection {
+        Reflection::from_transform(self.then(other.to_transform()))
+    }
+}
+
+impl Mul for Reflection {
+    type Output = Transform;
+
+    fn mul(self, other: Self) -> Transform {
+        self.then_reflection(other)
+    }
+}
+
+impl Mul<Point4> for Reflection {
+    type Output = Point4;
+
+    fn mul(self, point: Point4) -> Point4 {
+        self.transform(point)
+    }
+}
+
+impl Mul<Vec4> for Reflection {
+    type Output = Vec4;
+
+    fn mul(self, direction: Vec4) -> Vec4 {
+        self.transform_direction(direction)
+    }
+}
+
+/// The negated reflection mirrors every point the same way
+impl Neg for Reflection {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_transform(-self.to_transform())
+    }
+}
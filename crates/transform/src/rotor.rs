@@ -1,11 +1,14 @@
-use crate::GlobalTransform;
+use crate::{Bivector, DRotor, GlobalTransform, InvariantPlanes, Isoclinic, Point4, Vec4, simd};
 use bevy::{
     ecs::component::Component,
+    math::{Mat4, Quat, Vec3},
     reflect::{Reflect, prelude::ReflectDefault},
 };
 use bytemuck::{Pod, Zeroable};
+use std::ops::{Mul, MulAssign, Neg};
 
 #[derive(Component, Reflect, Debug, Clone, Copy, Zeroable, Pod)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[reflect(Default, Clone)]
 #[require(GlobalTransform)]
 #[repr(C)]
@@ -94,7 +97,7 @@ impl Rotor {
 
     #[must_use]
     pub const fn magnitude_squared(self) -> f32 {
-        self.then(self.inverse()).s
+        self.then_scalar(self.inverse()).s
     }
 
     #[must_use]
@@ -102,9 +105,16 @@ impl Rotor {
         self.magnitude_squared().sqrt()
     }
 
+    /// Projects back onto the valid rotations, normalising the left and right quaternions separately
+    /// also removes any drift that makes `self.then(self.inverse())` gain an e1234 part
     #[must_use]
     pub fn normalized(self) -> Self {
-        let inverse_magnitude = self.magnitude().recip();
+        let (left, right) = self.to_quaternion_pair();
+        Self::from_quaternion_pair(left.normalize(), right.normalize())
+    }
+
+    #[must_use]
+    pub const fn inverse(self) -> Self {
         let Self {
             s,
             e12,
@@ -116,19 +126,19 @@ impl Rotor {
             e1234,
         } = self;
         Self {
-            s: s * inverse_magnitude,
-            e12: e12 * inverse_magnitude,
-            e13: e13 * inverse_magnitude,
-            e14: e14 * inverse_magnitude,
-            e23: e23 * inverse_magnitude,
-            e24: e24 * inverse_magnitude,
-            e34: e34 * inverse_magnitude,
-            e1234: e1234 * inverse_magnitude,
+            s,
+            e12: -e12,
+            e13: -e13,
+            e14: -e14,
+            e23: -e23,
+            e24: -e24,
+            e34: -e34,
+            e1234,
         }
     }
 
     #[must_use]
-    pub const fn inverse(self) -> Self {
+    pub(crate) const fn negated(self) -> Self {
         let Self {
             s,
             e12,
@@ -140,19 +150,54 @@ impl Rotor {
             e1234,
         } = self;
         Self {
-            s,
+            s: -s,
             e12: -e12,
             e13: -e13,
             e14: -e14,
             e23: -e23,
             e24: -e24,
             e34: -e34,
-            e1234,
+            e1234: -e1234,
+        }
+    }
+
+    /// Whether every component is within `max_abs_diff` of the matching component of `other`
+    #[must_use]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f32) -> bool {
+        let a: [f32; 8] = bytemuck::cast(self);
+        let b: [f32; 8] = bytemuck::cast(other);
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() <= max_abs_diff)
+    }
+
+    /// Like [`Self::abs_diff_eq`] but also true when `other` is close to `-self`, which is the same rotation
+    #[must_use]
+    pub fn approx_eq(self, other: Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(other, max_abs_diff) || self.abs_diff_eq(-other, max_abs_diff)
+    }
+
+    #[must_use]
+    pub const fn as_drotor(self) -> DRotor {
+        DRotor {
+            s: self.s as f64,
+            e12: self.e12 as f64,
+            e13: self.e13 as f64,
+            e14: self.e14 as f64,
+            e23: self.e23 as f64,
+            e24: self.e24 as f64,
+            e34: self.e34 as f64,
+            e1234: self.e1234 as f64,
         }
     }
 
     #[must_use]
-    pub const fn then(self, other: Self) -> Self {
+    pub fn then(self, other: Self) -> Self {
+        simd::rotor_then(self, other)
+    }
+
+    /// [`Self::then`] without SIMD, which also works in const contexts, both agree up to rounding
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn then_scalar(self, other: Self) -> Self {
         let Self {
             s: a1,
             e12: b1,
@@ -186,7 +231,7 @@ impl Rotor {
     }
 
     #[must_use]
-    pub const fn rotate(self, direction: (f32, f32, f32, f32)) -> (f32, f32, f32, f32) {
+    pub const fn rotate(self, direction: Vec4) -> Vec4 {
         let Self {
             s: a,
             e12: b,
@@ -197,7 +242,12 @@ impl Rotor {
             e34: g,
             e1234: h,
         } = self;
-        let (p3, p2, p1, p0) = direction;
+        let Vec4 {
+            x: p3,
+            y: p2,
+            z: p1,
+            w: p0,
+        } = direction;
         let ap2 = a * p2;
         let bp3 = b * p3;
         let ep1 = e * p1;
@@ -224,7 +274,356 @@ impl Rotor {
             p2 + 2.0 * (h * (g * p3 - h * p2 - c * p0 - d * p1) + b * s1 - f * s3 - e * s2),
             p3 + 2.0 * (d * s3 + c * s2 + b * s0 - h * (g * p2 + h * p3 + e * p0 + f * p1)),
         ];
-        (x, y, z, w)
+        Vec4 { x, y, z, w }
+    }
+
+    /// Rotates every direction in place using the rotation matrix, which is only built once
+    pub fn rotate_directions(self, directions: &mut [Vec4]) {
+        let matrix = self.to_matrix4();
+        for direction in directions {
+            *direction = (matrix * bevy::math::Vec4::from(*direction)).into();
+        }
+    }
+
+    #[must_use]
+    pub const fn rotate_bivector(self, bivector: Bivector) -> Bivector {
+        let Bivector {
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+        } = bivector;
+        let Self {
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            ..
+        } = self
+            .then_scalar(Self {
+                s: 0.0,
+                e12,
+                e13,
+                e14,
+                e23,
+                e24,
+                e34,
+                e1234: 0.0,
+            })
+            .then_scalar(self.inverse());
+        Bivector {
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+        }
+    }
+
+    #[must_use]
+    pub fn exp(bivector: Bivector) -> Self {
+        let (left, right) = bivector_isoclinic_parts(bivector);
+        Self::from_quaternion_pair(quaternion_exp(left), quaternion_exp(right))
+    }
+
+    /// The principal logarithm, `Rotor::exp(rotor.log())` gives back `rotor`
+    #[must_use]
+    pub fn log(self) -> Bivector {
+        let (left, right) = self.to_quaternion_pair();
+        bivector_from_isoclinic_parts(quaternion_log(left), quaternion_log(right))
+    }
+
+    /// Normalised linear interpolation, takes the shorter path but doesnt move at a constant angular speed
+    #[must_use]
+    pub fn nlerp(self, other: Self, t: f32) -> Self {
+        // `other` and `-other` are the same rotation, so interpolate towards whichever is closer
+        let other = if self.then(other.inverse()).s < 0.0 {
+            other.negated()
+        } else {
+            other
+        };
+        let Self {
+            s: s1,
+            e12: e12_1,
+            e13: e13_1,
+            e14: e14_1,
+            e23: e23_1,
+            e24: e24_1,
+            e34: e34_1,
+            e1234: e1234_1,
+        } = self;
+        let Self {
+            s: s2,
+            e12: e12_2,
+            e13: e13_2,
+            e14: e14_2,
+            e23: e23_2,
+            e24: e24_2,
+            e34: e34_2,
+            e1234: e1234_2,
+        } = other;
+        Self {
+            s: s1 + (s2 - s1) * t,
+            e12: e12_1 + (e12_2 - e12_1) * t,
+            e13: e13_1 + (e13_2 - e13_1) * t,
+            e14: e14_1 + (e14_2 - e14_1) * t,
+            e23: e23_1 + (e23_2 - e23_1) * t,
+            e24: e24_1 + (e24_2 - e24_1) * t,
+            e34: e34_1 + (e34_2 - e34_1) * t,
+            e1234: e1234_1 + (e1234_2 - e1234_1) * t,
+        }
+        .normalized()
+    }
+
+    /// Interpolates along the shortest path at a constant angular speed, this also works for double rotations
+    #[must_use]
+    pub fn slerp(self, other: Self, t: f32) -> Self {
+        let mut difference = self.inverse().then(other);
+        // the negated difference is the same rotation, so take whichever is the shorter path
+        if difference.s < 0.0 {
+            difference = difference.negated();
+        }
+        self.then(Self::exp(difference.log().scaled(t)))
+    }
+
+    /// Splits the rotation into the two orthogonal planes it rotates within, the first plane has the larger angle,
+    /// `Rotor::from(rotor.invariant_planes())` gives back either `rotor` or `-rotor`
+    #[must_use]
+    pub fn invariant_planes(self) -> InvariantPlanes {
+        const ISOCLINIC_TOLERANCE: f32 = 1e-4;
+
+        // pick the sign with the smaller angles
+        let rotor = if self.s < 0.0 { self.negated() } else { self };
+        let (left, right) = rotor.to_quaternion_pair();
+        let isoclinic = match (
+            right.xyz().length() < ISOCLINIC_TOLERANCE,
+            left.xyz().length() < ISOCLINIC_TOLERANCE,
+        ) {
+            (true, true) => Some(Isoclinic::Both),
+            (true, false) => Some(Isoclinic::Left),
+            (false, true) => Some(Isoclinic::Right),
+            (false, false) => None,
+        };
+
+        let (left, right) = (quaternion_log(left), quaternion_log(right));
+        let (left_angle, right_angle) = (left.length(), right.length());
+        // the axis doesnt matter when the angle is zero
+        let left_axis = left.try_normalize().unwrap_or(Vec3::X);
+        let right_axis = right.try_normalize().unwrap_or(Vec3::X);
+
+        let first = bivector_from_isoclinic_parts(left_axis, right_axis);
+        let first_angle = left_angle + right_angle;
+        let second = bivector_from_isoclinic_parts(left_axis, -right_axis);
+        let second_angle = left_angle - right_angle;
+        InvariantPlanes {
+            first,
+            first_angle,
+            second: if second_angle < 0.0 {
+                second.scaled(-1.0)
+            } else {
+                second
+            },
+            second_angle: second_angle.abs(),
+            isoclinic,
+        }
+    }
+
+    /// The smallest rotation that turns the direction `from` to point along `to`
+    #[must_use]
+    pub fn from_to(from: Vec4, to: Vec4) -> Self {
+        let from = from.normalised_or_zero();
+        let to = to.normalised_or_zero();
+        let rotation = |from: Vec4, to: Vec4, s: f32| {
+            let Bivector {
+                e12,
+                e13,
+                e14,
+                e23,
+                e24,
+                e34,
+            } = from.wedge(to);
+            Self {
+                s,
+                e12,
+                e13,
+                e14,
+                e23,
+                e24,
+                e34,
+                e1234: 0.0,
+            }
+        };
+
+        let s = 1.0 + from.dot(to);
+        if s > 1e-6 {
+            rotation(from, to, s).normalized()
+        } else {
+            // opposite directions, so any plane containing `from` works for a half turn
+            let axis = Vec4::AXES
+                .into_iter()
+                .min_by(|a, b| a.dot(from).abs().total_cmp(&b.dot(from).abs()))
+                .unwrap();
+            let other = (axis - from * axis.dot(from)).normalised();
+            rotation(from, other, 0.0).normalized()
+        }
+    }
+
+    /// The columns are where each axis gets rotated to
+    #[must_use]
+    pub const fn to_matrix4(self) -> Mat4 {
+        Mat4::from_cols_array_2d(&[
+            self.rotate(Vec4::X).to_array(),
+            self.rotate(Vec4::Y).to_array(),
+            self.rotate(Vec4::Z).to_array(),
+            self.rotate(Vec4::W).to_array(),
+        ])
+    }
+
+    /// Finds the rotation closest to `matrix`, so it doesnt need to be exactly orthonormal
+    #[must_use]
+    pub fn from_matrix4(matrix: Mat4) -> Self {
+        use bevy::math::Vec4;
+
+        // a quaternion written as a direction, the scalar part is the x axis
+        let to_direction = |q: Quat| Vec4::new(q.w, q.x, q.y, q.z);
+        let to_quaternion = |v: Vec4| Quat::from_xyzw(v.y, v.z, v.w, v.x);
+        let basis = [Vec4::X, Vec4::Y, Vec4::Z, Vec4::W].map(to_quaternion);
+
+        // every rotation matrix is `left * direction * right.conjugate()` for some quaternions,
+        // and projecting the matrix onto each of those products gives the outer product of `left` and `right`
+        let mut associate = Mat4::ZERO;
+        for (a, &left) in basis.iter().enumerate() {
+            for (b, &right) in basis.iter().enumerate() {
+                let product = basis.iter().enumerate().map(|(k, &axis)| {
+                    matrix
+                        .col(k)
+                        .dot(to_direction(left * axis * right.conjugate()))
+                });
+                associate.col_mut(b)[a] = product.sum::<f32>() * 0.25;
+            }
+        }
+
+        // the closest rotation comes from the largest singular vectors of that outer product
+        let mut left = (0..4)
+            .map(|b| associate.col(b))
+            .max_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+            .unwrap()
+            .normalize_or(Vec4::X);
+        let mut right = Vec4::X;
+        for _ in 0..4 {
+            right = (associate.transpose() * left).normalize_or(Vec4::X);
+            left = (associate * right).normalize_or(Vec4::X);
+        }
+        Self::from_quaternion_pair(to_quaternion(left), to_quaternion(right))
+    }
+
+    /// Rotates by `angle` within `plane` and within its orthogonal complement,
+    /// a left isoclinic rotation turns both planes the same way as a left quaternion multiplication
+    #[must_use]
+    pub fn left_isoclinic(plane: Bivector, angle: f32) -> Self {
+        let (left, _) = bivector_isoclinic_parts(plane);
+        Self::from_quaternion_pair(
+            quaternion_exp(left.normalize_or_zero() * angle),
+            Quat::IDENTITY,
+        )
+    }
+
+    /// Rotates by `angle` within `plane` and within its orthogonal complement,
+    /// a right isoclinic rotation turns both planes the same way as a right quaternion multiplication
+    #[must_use]
+    pub fn right_isoclinic(plane: Bivector, angle: f32) -> Self {
+        let (_, right) = bivector_isoclinic_parts(plane);
+        Self::from_quaternion_pair(
+            Quat::IDENTITY,
+            quaternion_exp(right.normalize_or_zero() * angle),
+        )
+    }
+
+    /// Converts to the left and right unit quaternions of SO(4), which rotate a direction as
+    /// `left * (x + yi + zj + wk) * right.conjugate()`, the direction being `Quat::from_xyzw(y, z, w, x)`
+    #[must_use]
+    pub const fn to_quaternion_pair(self) -> (Quat, Quat) {
+        let Self {
+            s,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            e1234,
+        } = self;
+        (
+            Quat::from_xyzw(e12 + e34, e24 - e13, e14 + e23, s - e1234),
+            Quat::from_xyzw(e34 - e12, e13 + e24, e23 - e14, s + e1234),
+        )
+    }
+
+    #[must_use]
+    pub fn from_quaternion_pair(left: Quat, right: Quat) -> Self {
+        Self {
+            s: (left.w + right.w) * 0.5,
+            e12: (left.x - right.x) * 0.5,
+            e13: (right.y - left.y) * 0.5,
+            e14: (left.z - right.z) * 0.5,
+            e23: (left.z + right.z) * 0.5,
+            e24: (left.y + right.y) * 0.5,
+            e34: (left.x + right.x) * 0.5,
+            e1234: (right.w - left.w) * 0.5,
+        }
+    }
+}
+
+/// The same split as [`Rotor::to_quaternion_pair`], a bivector exponentiates to pure quaternions on either side
+pub(crate) const fn bivector_isoclinic_parts(bivector: Bivector) -> (Vec3, Vec3) {
+    let Bivector {
+        e12,
+        e13,
+        e14,
+        e23,
+        e24,
+        e34,
+    } = bivector;
+    (
+        Vec3::new(e12 + e34, e24 - e13, e14 + e23),
+        Vec3::new(e34 - e12, e13 + e24, e23 - e14),
+    )
+}
+
+pub(crate) const fn bivector_from_isoclinic_parts(left: Vec3, right: Vec3) -> Bivector {
+    Bivector {
+        e12: (left.x - right.x) * 0.5,
+        e13: (right.y - left.y) * 0.5,
+        e14: (left.z - right.z) * 0.5,
+        e23: (left.z + right.z) * 0.5,
+        e24: (left.y + right.y) * 0.5,
+        e34: (left.x + right.x) * 0.5,
+    }
+}
+
+pub(crate) fn quaternion_exp(vector: Vec3) -> Quat {
+    let angle = vector.length();
+    let (sin, cos) = angle.sin_cos();
+    let scale = if angle > 0.0 { sin / angle } else { 1.0 };
+    Quat::from_vec4((vector * scale).extend(cos))
+}
+
+pub(crate) fn quaternion_log(quaternion: Quat) -> Vec3 {
+    let vector = quaternion.xyz();
+    let length = vector.length();
+    let angle = length.atan2(quaternion.w);
+    if length > 0.0 {
+        vector * (angle / length)
+    } else if quaternion.w < 0.0 {
+        // every axis is equally valid for a half turn
+        Vec3::new(angle, 0.0, 0.0)
+    } else {
+        Vec3::ZERO
     }
 }
 
@@ -233,3 +632,290 @@ impl Default for Rotor {
         Self::IDENTITY
     }
 }
+
+/// `a * b` is `a.then(b)`, so `(a * b) * direction == a * (b * direction)`
+impl Mul for Rotor {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.then(other)
+    }
+}
+
+impl MulAssign for Rotor {
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.then(other);
+    }
+}
+
+impl Mul<Vec4> for Rotor {
+    type Output = Vec4;
+
+    fn mul(self, direction: Vec4) -> Vec4 {
+        self.rotate(direction)
+    }
+}
+
+/// Rotates the point around the origin
+impl Mul<Point4> for Rotor {
+    type Output = Point4;
+
+    fn mul(self, point: Point4) -> Point4 {
+        Point4::from_vector(self.rotate(point.to_vector()))
+    }
+}
+
+/// The negated rotor is the same rotation
+impl Neg for Rotor {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.negated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::rotors;
+
+    const TOLERANCE: f32 = 1e-4;
+
+    #[test]
+    fn exp_log_round_trip() {
+        for rotor in rotors() {
+            assert!(
+                Rotor::exp(rotor.log()).approx_eq(rotor, TOLERANCE),
+                "{rotor:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn log_exp_round_trip() {
+        for bivector in [
+            Bivector::ZERO,
+            Bivector::rotation_xy(1e-6),
+            Bivector::rotation_yz(1.0),
+            Bivector::rotation_xw(0.5).scaled(2.0),
+            Bivector {
+                e12: 0.3,
+                e13: -0.2,
+                e14: 0.1,
+                e23: 0.4,
+                e24: -0.5,
+                e34: 0.25,
+            },
+        ] {
+            let log = Rotor::exp(bivector).log();
+            assert!(log.abs_diff_eq(bivector, TOLERANCE), "{bivector:?}");
+        }
+    }
+
+    #[test]
+    fn quaternion_pair_rotates_like_the_rotor() {
+        let directions = [
+            Vec4::X,
+            Vec4::Y,
+            Vec4::Z,
+            Vec4::W,
+            Vec4::new(0.3, -1.2, 2.0, 0.7),
+        ];
+        for rotor in rotors() {
+            let (left, right) = rotor.to_quaternion_pair();
+            for direction in directions {
+                let Vec4 { x, y, z, w } = direction;
+                let moved = left * Quat::from_xyzw(y, z, w, x) * right.conjugate();
+                let moved = Vec4::new(moved.w, moved.x, moved.y, moved.z);
+                assert!(
+                    moved.abs_diff_eq(rotor.rotate(direction), TOLERANCE),
+                    "{rotor:?} {direction:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn quaternion_pair_round_trip() {
+        for rotor in rotors() {
+            let (left, right) = rotor.to_quaternion_pair();
+            assert!(
+                Rotor::from_quaternion_pair(left, right).abs_diff_eq(rotor, TOLERANCE),
+                "{rotor:?}"
+            );
+        }
+        let left = Quat::from_xyzw(0.1, -0.5, 0.3, 0.8).normalize();
+        let right = Quat::from_xyzw(-0.7, 0.2, 0.4, -0.1).normalize();
+        let (out_left, out_right) = Rotor::from_quaternion_pair(left, right).to_quaternion_pair();
+        assert!(out_left.abs_diff_eq(left, TOLERANCE));
+        assert!(out_right.abs_diff_eq(right, TOLERANCE));
+    }
+
+    #[test]
+    fn from_to_turns_from_onto_to() {
+        let directions = [
+            Vec4::X,
+            Vec4::Y,
+            Vec4::new(0.3, -1.2, 2.0, 0.7),
+            Vec4::new(-1.0, 0.5, 0.0, 4.0),
+        ];
+        for from in directions {
+            for to in directions {
+                let rotor = Rotor::from_to(from, to * 3.0);
+                assert!(
+                    rotor
+                        .rotate(from.normalised())
+                        .abs_diff_eq(to.normalised(), TOLERANCE),
+                    "{from:?} {to:?}"
+                );
+                // the smallest rotation is a single rotation by the angle between them
+                let planes = rotor.invariant_planes();
+                let angle = from
+                    .normalised()
+                    .dot(to.normalised())
+                    .clamp(-1.0, 1.0)
+                    .acos();
+                assert!(
+                    (planes.first_angle.abs() - angle).abs() < 1e-3,
+                    "{from:?} {to:?}"
+                );
+                assert!(planes.second_angle.abs() < 1e-3, "{from:?} {to:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn from_to_anti_parallel() {
+        for from in [Vec4::X, Vec4::W, Vec4::new(0.3, -1.2, 2.0, 0.7)] {
+            let rotor = Rotor::from_to(from, -from);
+            assert!(
+                rotor
+                    .rotate(from.normalised())
+                    .abs_diff_eq(-from.normalised(), TOLERANCE),
+                "{from:?}"
+            );
+            // a single half turn, so the directions orthogonal to that plane stay put
+            let planes = rotor.invariant_planes();
+            assert!((planes.first_angle.abs() - std::f32::consts::PI).abs() < 1e-3);
+            assert!(planes.second_angle.abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn operators_compose_like_applying_in_turn() {
+        let direction = Vec4::new(0.3, -1.2, 2.0, 0.7);
+        let point = Point4::new(1.0, -2.0, 3.5, 0.25);
+        for (a, b) in pairs() {
+            assert!(((a * b) * direction).abs_diff_eq(a * (b * direction), TOLERANCE));
+            assert!(((a * b) * point).abs_diff_eq(a * (b * point), TOLERANCE));
+            // the negation is the same rotation
+            assert!((-a * direction).abs_diff_eq(a * direction, TOLERANCE));
+            assert!((-a).approx_eq(a, 0.0));
+            let mut composed = a;
+            composed *= b;
+            assert!(composed.abs_diff_eq(a * b, 0.0));
+            assert!((a * b).abs_diff_eq(a.then(b), 0.0));
+        }
+    }
+
+    /// Each fixture rotor paired with the next one
+    fn pairs() -> Vec<(Rotor, Rotor)> {
+        let rotors = rotors();
+        let next = rotors.iter().copied().cycle().skip(1);
+        rotors.iter().copied().zip(next).collect()
+    }
+
+    #[test]
+    fn interpolation_endpoints() {
+        for (a, b) in pairs() {
+            assert!(a.nlerp(b, 0.0).approx_eq(a, TOLERANCE), "{a:?} {b:?}");
+            assert!(a.nlerp(b, 1.0).approx_eq(b, TOLERANCE), "{a:?} {b:?}");
+            assert!(a.slerp(b, 0.0).approx_eq(a, TOLERANCE), "{a:?} {b:?}");
+            assert!(a.slerp(b, 1.0).approx_eq(b, TOLERANCE), "{a:?} {b:?}");
+        }
+    }
+
+    #[test]
+    fn interpolation_takes_the_shorter_path() {
+        let start = Rotor::IDENTITY;
+        let end = Rotor::rotation_xy(1.0);
+        let halfway = Rotor::rotation_xy(0.5);
+        // `-end` is the same rotation, the long way round would pass through a rotation of pi + 0.5
+        for end in [end, -end] {
+            assert!(start.nlerp(end, 0.5).approx_eq(halfway, TOLERANCE));
+            assert!(start.slerp(end, 0.5).approx_eq(halfway, TOLERANCE));
+        }
+        for (a, b) in pairs() {
+            // exactly half a turn apart both ways are as short, so either answer is fine
+            if a.inverse().then(b).s.abs() < 0.1 {
+                continue;
+            }
+            for t in [0.25, 0.5, 0.75] {
+                assert!(
+                    a.nlerp(-b, t).approx_eq(a.nlerp(b, t), TOLERANCE),
+                    "{a:?} {b:?} {t}"
+                );
+                assert!(
+                    a.slerp(-b, t).approx_eq(a.slerp(b, t), TOLERANCE),
+                    "{a:?} {b:?} {t}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn slerp_moves_at_a_constant_speed() {
+        const STEPS: usize = 8;
+        for (a, b) in pairs() {
+            let points: Vec<Rotor> = (0..=STEPS)
+                .map(|i| a.slerp(b, i as f32 / STEPS as f32))
+                .collect();
+            let first_step = points[0].inverse().then(points[1]);
+            for window in points.windows(2) {
+                let step = window[0].inverse().then(window[1]);
+                assert!(step.approx_eq(first_step, TOLERANCE), "{a:?} {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn matrix4_round_trip() {
+        for rotor in rotors() {
+            let matrix = rotor.to_matrix4();
+            assert!(
+                Rotor::from_matrix4(matrix).approx_eq(rotor, TOLERANCE),
+                "{rotor:?}"
+            );
+            // the closest rotation to a scaled and slightly skewed matrix is the same one
+            let mut skewed = matrix * 1.5;
+            skewed.x_axis.y += 1e-3;
+            assert!(
+                Rotor::from_matrix4(skewed).approx_eq(rotor, 1e-3),
+                "{rotor:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn from_matrix4_is_orthonormal() {
+        let mut seed = 0x2545_f491_u32;
+        let mut noise = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            (seed as f32 / u32::MAX as f32 - 0.5) * 0.2
+        };
+        for rotor in rotors() {
+            let perturbed =
+                Mat4::from_cols_array(&rotor.to_matrix4().to_cols_array().map(|x| x + noise()));
+            let rotor = Rotor::from_matrix4(perturbed);
+            assert!((rotor.magnitude() - 1.0).abs() < TOLERANCE, "{rotor:?}");
+            let matrix = rotor.to_matrix4();
+            assert!(
+                (matrix.transpose() * matrix).abs_diff_eq(Mat4::IDENTITY, TOLERANCE),
+                "{rotor:?}"
+            );
+            assert!((matrix.determinant() - 1.0).abs() < TOLERANCE, "{rotor:?}");
+        }
+    }
+}
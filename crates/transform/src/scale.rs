@@ -0,0 +1,111 @@
+use crate::{GlobalTransform, Vec4};
+use bevy::{
+    ecs::component::Component,
+    reflect::{Reflect, prelude::ReflectDefault},
+};
+use std::ops::{Mul, MulAssign};
+
+/// Scales an entity and its children before its [`crate::Reflection`] and [`crate::Transform`] are applied
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[require(GlobalTransform)]
+pub enum Scale {
+    Uniform(f32),
+    /// Scales each local axis separately, children rotated relative to the axes combine with it approximately
+    PerAxis(Vec4),
+}
+
+impl Scale {
+    pub const ONE: Self = Self::Uniform(1.0);
+
+    /// The factor along each local axis
+    #[must_use]
+    pub const fn to_vector(self) -> Vec4 {
+        match self {
+            Self::Uniform(scale) => Vec4::splat(scale),
+            Self::PerAxis(scale) => scale,
+        }
+    }
+
+    /// The largest factor, for shapes that cant be stretched along a single axis
+    #[must_use]
+    pub const fn max_factor(self) -> f32 {
+        match self {
+            Self::Uniform(scale) => scale.abs(),
+            Self::PerAxis(Vec4 { x, y, z, w }) => x.abs().max(y.abs()).max(z.abs()).max(w.abs()),
+        }
+    }
+
+    #[must_use]
+    pub const fn inverse(self) -> Self {
+        match self {
+            Self::Uniform(scale) => Self::Uniform(scale.recip()),
+            Self::PerAxis(Vec4 { x, y, z, w }) => {
+                Self::PerAxis(Vec4::new(x.recip(), y.recip(), z.recip(), w.recip()))
+            }
+        }
+    }
+
+    #[must_use]
+    pub const fn then(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Uniform(a), Self::Uniform(b)) => Self::Uniform(a * b),
+            (a, b) => Self::PerAxis(a.scale(b.to_vector())),
+        }
+    }
+
+    /// Scales a position or direction along the local axes
+    #[must_use]
+    pub const fn scale(self, vector: Vec4) -> Vec4 {
+        let Vec4 { x, y, z, w } = self.to_vector();
+        Vec4::new(vector.x * x, vector.y * y, vector.z * z, vector.w * w)
+    }
+}
+
+impl Default for Scale {
+    fn default() -> Self {
+        Self::ONE
+    }
+}
+
+impl From<f32> for Scale {
+    fn from(scale: f32) -> Self {
+        Self::Uniform(scale)
+    }
+}
+
+impl From<Vec4> for Scale {
+    fn from(scale: Vec4) -> Self {
+        Self::PerAxis(scale)
+    }
+}
+
+/// `a * b` is `a.then(b)`
+impl Mul for Scale {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.then(other)
+    }
+}
+
+impl MulAssign for Scale {
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.then(other);
+    }
+}
+
+impl Mul<Vec4> for Scale {
+    type Output = Vec4;
+
+    fn mul(self, vector: Vec4) -> Vec4 {
+        self.scale(vector)
+    }
+}
+
+/// The combined [`Scale`] of an entity and all its parents, positions in the [`GlobalTransform`] already include it
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+pub struct GlobalScale(pub Scale);
@@ -0,0 +1,195 @@
+use crate::{Bivector, Vec4};
+use bevy::reflect::{Reflect, prelude::ReflectDefault};
+use bytemuck::{Pod, Zeroable};
+use std::ops::{Add, Sub};
+
+/// The generator of a [`Transform`](crate::Transform), a bivector of 4D PGA describing a screw motion
+#[derive(Reflect, Debug, Default, Clone, Copy, Zeroable, Pod)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[repr(C)]
+pub struct Screw {
+    pub e01: f32,
+    pub e02: f32,
+    pub e03: f32,
+    pub e04: f32,
+    pub e12: f32,
+    pub e13: f32,
+    pub e14: f32,
+    pub e23: f32,
+    pub e24: f32,
+    pub e34: f32,
+}
+
+impl Screw {
+    pub const ZERO: Self = Self {
+        e01: 0.0,
+        e02: 0.0,
+        e03: 0.0,
+        e04: 0.0,
+        e12: 0.0,
+        e13: 0.0,
+        e14: 0.0,
+        e23: 0.0,
+        e24: 0.0,
+        e34: 0.0,
+    };
+
+    #[must_use]
+    pub const fn translation(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self {
+            e01: x * 0.5,
+            e02: y * -0.5,
+            e03: z * 0.5,
+            e04: w * -0.5,
+            ..Self::ZERO
+        }
+    }
+
+    #[must_use]
+    pub const fn from_bivector(bivector: Bivector) -> Self {
+        let Bivector {
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+        } = bivector;
+        Self {
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            ..Self::ZERO
+        }
+    }
+
+    #[must_use]
+    pub const fn rotation_xy(angle: f32) -> Self {
+        Self::from_bivector(Bivector::rotation_xy(angle))
+    }
+
+    #[must_use]
+    pub const fn rotation_xz(angle: f32) -> Self {
+        Self::from_bivector(Bivector::rotation_xz(angle))
+    }
+
+    #[must_use]
+    pub const fn rotation_xw(angle: f32) -> Self {
+        Self::from_bivector(Bivector::rotation_xw(angle))
+    }
+
+    #[must_use]
+    pub const fn rotation_yz(angle: f32) -> Self {
+        Self::from_bivector(Bivector::rotation_yz(angle))
+    }
+
+    #[must_use]
+    pub const fn rotation_yw(angle: f32) -> Self {
+        Self::from_bivector(Bivector::rotation_yw(angle))
+    }
+
+    #[must_use]
+    pub const fn rotation_zw(angle: f32) -> Self {
+        Self::from_bivector(Bivector::rotation_zw(angle))
+    }
+
+    #[must_use]
+    pub const fn scaled(self, scale: f32) -> Self {
+        let Self {
+            e01,
+            e02,
+            e03,
+            e04,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+        } = self;
+        Self {
+            e01: e01 * scale,
+            e02: e02 * scale,
+            e03: e03 * scale,
+            e04: e04 * scale,
+            e12: e12 * scale,
+            e13: e13 * scale,
+            e14: e14 * scale,
+            e23: e23 * scale,
+            e24: e24 * scale,
+            e34: e34 * scale,
+        }
+    }
+
+    #[must_use]
+    pub const fn bivector_part(self) -> Bivector {
+        let Self {
+            e01: _,
+            e02: _,
+            e03: _,
+            e04: _,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+        } = self;
+        Bivector {
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+        }
+    }
+
+    /// The translation this screw would apply if it had no rotational part
+    #[must_use]
+    pub const fn translation_part(self) -> Vec4 {
+        Vec4 {
+            x: self.e01 * 2.0,
+            y: self.e02 * -2.0,
+            z: self.e03 * 2.0,
+            w: self.e04 * -2.0,
+        }
+    }
+}
+
+impl From<Bivector> for Screw {
+    fn from(bivector: Bivector) -> Self {
+        Self::from_bivector(bivector)
+    }
+}
+
+impl Add for Screw {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            e01: self.e01 + other.e01,
+            e02: self.e02 + other.e02,
+            e03: self.e03 + other.e03,
+            e04: self.e04 + other.e04,
+            e12: self.e12 + other.e12,
+            e13: self.e13 + other.e13,
+            e14: self.e14 + other.e14,
+            e23: self.e23 + other.e23,
+            e24: self.e24 + other.e24,
+            e34: self.e34 + other.e34,
+        }
+    }
+}
+
+impl Sub for Screw {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + other.scaled(-1.0)
+    }
+}
@@ -0,0 +1,30 @@
+// the same targets bevy::math uses for its own vectors, so the lanes are never emulated
+#[cfg(any(
+    target_feature = "sse2",
+    target_feature = "simd128",
+    target_arch = "aarch64"
+))]
+mod lanes;
+
+#[cfg(not(any(
+    target_feature = "sse2",
+    target_feature = "simd128",
+    target_arch = "aarch64"
+)))]
+mod lanes {
+    use crate::{Point4, Rotor, Transform};
+
+    pub(crate) const fn rotor_then(a: Rotor, b: Rotor) -> Rotor {
+        a.then_scalar(b)
+    }
+
+    pub(crate) const fn transform_then(a: Transform, b: Transform) -> Transform {
+        a.then_scalar(b)
+    }
+
+    pub(crate) const fn transform_point(transform: Transform, point: Point4) -> Point4 {
+        transform.transform_scalar(point)
+    }
+}
+
+pub(crate) use lanes::{rotor_then, transform_point, transform_then};
@@ -0,0 +1,298 @@
+use crate::{Point4, Rotor, Transform};
+use bevy::math::{Vec4 as Lanes, Vec4Swizzles};
+
+// the products split the components into blocks of four that are closed under multiplying by
+// `s`, `e12`, `e34` and `e1234`, so every term is a broadcast times a swizzle of a whole block
+
+// the sign of each lane, P for positive and N for negative
+const NPNP: Lanes = Lanes::new(-1.0, 1.0, -1.0, 1.0);
+const PNPN: Lanes = Lanes::new(1.0, -1.0, 1.0, -1.0);
+const NNPP: Lanes = Lanes::new(-1.0, -1.0, 1.0, 1.0);
+const PPNN: Lanes = Lanes::new(1.0, 1.0, -1.0, -1.0);
+const PNNP: Lanes = Lanes::new(1.0, -1.0, -1.0, 1.0);
+const NPPN: Lanes = Lanes::new(-1.0, 1.0, 1.0, -1.0);
+
+const fn rotor_lanes(rotor: Rotor) -> [Lanes; 2] {
+    let Rotor {
+        s,
+        e12,
+        e13,
+        e14,
+        e23,
+        e24,
+        e34,
+        e1234,
+    } = rotor;
+    [
+        Lanes::new(s, e12, e34, e1234),
+        Lanes::new(e13, e23, e14, e24),
+    ]
+}
+
+fn rotor_from_lanes([r0, r1]: [Lanes; 2]) -> Rotor {
+    let [s, e12, e34, e1234] = r0.to_array();
+    let [e13, e23, e14, e24] = r1.to_array();
+    Rotor {
+        s,
+        e12,
+        e13,
+        e14,
+        e23,
+        e24,
+        e34,
+        e1234,
+    }
+}
+
+const fn transform_lanes(transform: Transform) -> [Lanes; 4] {
+    let Transform {
+        s,
+        e01,
+        e02,
+        e03,
+        e04,
+        e12,
+        e13,
+        e14,
+        e23,
+        e24,
+        e34,
+        e0123,
+        e0124,
+        e0134,
+        e0234,
+        e1234,
+    } = transform;
+    [
+        Lanes::new(s, e12, e34, e1234),
+        Lanes::new(e13, e23, e14, e24),
+        Lanes::new(e01, e02, e0134, e0234),
+        Lanes::new(e03, e0123, e04, e0124),
+    ]
+}
+
+fn transform_from_lanes([r0, r1, d0, d1]: [Lanes; 4]) -> Transform {
+    let [s, e12, e34, e1234] = r0.to_array();
+    let [e13, e23, e14, e24] = r1.to_array();
+    let [e01, e02, e0134, e0234] = d0.to_array();
+    let [e03, e0123, e04, e0124] = d1.to_array();
+    Transform {
+        s,
+        e01,
+        e02,
+        e03,
+        e04,
+        e12,
+        e13,
+        e14,
+        e23,
+        e24,
+        e34,
+        e0123,
+        e0124,
+        e0134,
+        e0234,
+        e1234,
+    }
+}
+
+pub(crate) fn rotor_then(a: Rotor, b: Rotor) -> Rotor {
+    let [ar0, ar1] = rotor_lanes(a);
+    let [br0, br1] = rotor_lanes(b);
+    let r0 = ar0.xxxx() * br0
+        + ar0.yyyy() * NPNP * br0.yxwz()
+        + ar0.zzzz() * NNPP * br0.zwxy()
+        + ar0.wwww() * PNNP * br0.wzyx()
+        - ar1.xxxx() * br1
+        + ar1.yyyy() * NPNP * br1.yxwz()
+        + ar1.zzzz() * NNPP * br1.zwxy()
+        + ar1.wwww() * NPPN * br1.wzyx();
+    let r1 = ar0.xxxx() * br1
+        + ar0.yyyy() * PNPN * br1.yxwz()
+        + ar0.zzzz() * PPNN * br1.zwxy()
+        + ar0.wwww() * PNNP * br1.wzyx()
+        + ar1.xxxx() * br0
+        + ar1.yyyy() * NPNP * br0.yxwz()
+        + ar1.zzzz() * NNPP * br0.zwxy()
+        + ar1.wwww() * PNNP * br0.wzyx();
+    rotor_from_lanes([r0, r1])
+}
+
+pub(crate) fn transform_then(a: Transform, b: Transform) -> Transform {
+    let [ar0, ar1, ad0, ad1] = transform_lanes(a);
+    let [br0, br1, bd0, bd1] = transform_lanes(b);
+    let r0 = ar0.xxxx() * br0
+        + ar0.yyyy() * NPNP * br0.yxwz()
+        + ar0.zzzz() * NNPP * br0.zwxy()
+        + ar0.wwww() * PNNP * br0.wzyx()
+        - ar1.xxxx() * br1
+        + ar1.yyyy() * NPNP * br1.yxwz()
+        + ar1.zzzz() * NNPP * br1.zwxy()
+        + ar1.wwww() * NPPN * br1.wzyx();
+    let r1 = ar0.xxxx() * br1
+        + ar0.yyyy() * PNPN * br1.yxwz()
+        + ar0.zzzz() * PPNN * br1.zwxy()
+        + ar0.wwww() * PNNP * br1.wzyx()
+        + ar1.xxxx() * br0
+        + ar1.yyyy() * NPNP * br0.yxwz()
+        + ar1.zzzz() * NNPP * br0.zwxy()
+        + ar1.wwww() * PNNP * br0.wzyx();
+    let d0 = ar0.xxxx() * bd0
+        + ar0.yyyy() * PNPN * bd0.yxwz()
+        + ar0.zzzz() * NNPP * bd0.zwxy()
+        + ar0.wwww() * NPPN * bd0.wzyx()
+        + ar1.xxxx() * bd1
+        + ar1.yyyy() * NPNP * bd1.yxwz()
+        + ar1.zzzz() * PPNN * bd1.zwxy()
+        + ar1.wwww() * NPPN * bd1.wzyx()
+        + ad0.xxxx() * br0
+        + ad0.yyyy() * NPNP * br0.yxwz()
+        + ad0.zzzz() * NNPP * br0.zwxy()
+        + ad0.wwww() * PNNP * br0.wzyx()
+        - ad1.xxxx() * br1
+        + ad1.yyyy() * NPNP * br1.yxwz()
+        + ad1.zzzz() * NNPP * br1.zwxy()
+        + ad1.wwww() * NPPN * br1.wzyx();
+    let d1 = ar0.xxxx() * bd1
+        + ar0.yyyy() * NPNP * bd1.yxwz()
+        + ar0.zzzz() * PPNN * bd1.zwxy()
+        + ar0.wwww() * NPPN * bd1.wzyx()
+        - ar1.xxxx() * bd0
+        + ar1.yyyy() * NPNP * bd0.yxwz()
+        + ar1.zzzz() * PPNN * bd0.zwxy()
+        + ar1.wwww() * PNNP * bd0.wzyx()
+        + ad0.xxxx() * br1
+        + ad0.yyyy() * PNPN * br1.yxwz()
+        + ad0.zzzz() * PPNN * br1.zwxy()
+        + ad0.wwww() * PNNP * br1.wzyx()
+        + ad1.xxxx() * br0
+        + ad1.yyyy() * NPNP * br0.yxwz()
+        + ad1.zzzz() * NNPP * br0.zwxy()
+        + ad1.wwww() * PNNP * br0.wzyx();
+    transform_from_lanes([r0, r1, d0, d1])
+}
+
+/// The same polynomial as [`Transform::transform_scalar`] with the four output components in the lanes,
+/// so both agree up to rounding for any motor, normalised or not
+pub(crate) fn transform_point(transform: Transform, point: Point4) -> Point4 {
+    let Transform {
+        s: a,
+        e01: b,
+        e02: c,
+        e03: d,
+        e04: f,
+        e12: g,
+        e13: h,
+        e14: i,
+        e23: j,
+        e24: k,
+        e34: l,
+        e0123: m,
+        e0124: n,
+        e0134: o,
+        e0234: p,
+        e1234: q,
+    } = transform;
+    let Point4 { x, y, z, w } = point;
+    // the lanes are in the order w, z, y, x like the names in the scalar version
+    let point = Lanes::new(w, z, y, x);
+    let [p0, p1, p2, p3] = [w, z, y, x].map(Lanes::splat);
+
+    let s = Lanes::new(c, b, d, f)
+        + p0 * Lanes::new(-k, -i, -l, -a)
+        + p1 * Lanes::new(j, h, a, -l)
+        + p2 * Lanes::new(-a, -g, j, k)
+        + p3 * Lanes::new(-g, a, -h, -i);
+    let t = Lanes::new(m, n, o, p)
+        + p0 * Lanes::new(-q, -g, -h, -j)
+        + p1 * Lanes::new(g, -q, -i, -k)
+        + p2 * Lanes::new(h, i, -q, -l)
+        + p3 * Lanes::new(j, k, l, -q);
+    let sum = Lanes::splat(q) * t
+        + s.xxxx() * Lanes::new(k, -j, 0.0, g)
+        + s.yyyy() * Lanes::new(i, -h, g, 0.0)
+        + s.zzzz() * Lanes::new(l, 0.0, -j, h)
+        + s.wwww() * Lanes::new(0.0, l, -k, i)
+        + Lanes::splat(a) * Lanes::new(-f, d, -c, b)
+        + Lanes::splat(m) * Lanes::new(0.0, g, h, j)
+        + Lanes::splat(n) * Lanes::new(-g, 0.0, i, k)
+        + Lanes::splat(o) * Lanes::new(-h, -i, 0.0, l)
+        + Lanes::splat(p) * Lanes::new(-j, -k, -l, 0.0);
+
+    let [w, z, y, x] = (point + sum * 2.0).to_array();
+    Point4::new(x, y, z, w)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::arbitrary_transforms;
+
+    /// Points a few units from the origin, like the ones in the demo
+    fn points() -> [Point4; 4] {
+        [
+            Point4::ORIGIN,
+            Point4::new(1.0, 2.0, -3.0, 0.5),
+            Point4::new(-4.0, 0.0, 0.25, 3.0),
+            Point4::new(0.5, -0.5, 0.5, -0.5),
+        ]
+    }
+
+    // the products are sums of 16 terms of components in -1..1, so rounding stays well below these
+    const PRODUCT_TOLERANCE: f32 = 1e-5;
+    const POINT_TOLERANCE: f32 = 1e-4;
+
+    #[test]
+    fn transform_then_matches_scalar() {
+        // the geometric product is the same polynomial either way, so this holds for non-normalised input too
+        let transforms = arbitrary_transforms(32);
+        for (&a, &b) in transforms.iter().zip(transforms.iter().rev()) {
+            assert!(a.then(b).abs_diff_eq(a.then_scalar(b), PRODUCT_TOLERANCE));
+            let (a, b) = (a.normalised(), b.normalised());
+            assert!(a.then(b).abs_diff_eq(a.then_scalar(b), PRODUCT_TOLERANCE));
+        }
+    }
+
+    #[test]
+    fn rotor_then_matches_scalar() {
+        let rotors = arbitrary_transforms(32)
+            .into_iter()
+            .map(Transform::rotor_part)
+            .collect::<Vec<_>>();
+        for (&a, &b) in rotors.iter().zip(rotors.iter().rev()) {
+            assert!(a.then(b).abs_diff_eq(a.then_scalar(b), PRODUCT_TOLERANCE));
+        }
+    }
+
+    #[test]
+    fn transform_point_matches_scalar() {
+        // both are the same polynomial, so this holds for motors that arent normalised too
+        for transform in arbitrary_transforms(32) {
+            let scaled: Transform =
+                bytemuck::cast(bytemuck::cast::<_, [f32; 16]>(transform).map(|x| x * 1.5));
+            for transform in [transform, transform.normalised(), scaled] {
+                for point in points() {
+                    assert!(
+                        transform
+                            .transform(point)
+                            .abs_diff_eq(transform.transform_scalar(point), POINT_TOLERANCE),
+                        "{transform:?} {point:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transform_points_matches_each_point() {
+        for transform in arbitrary_transforms(32) {
+            let transform = transform.normalised();
+            let mut points = points();
+            transform.transform_points(&mut points);
+            for (point, moved) in self::points().into_iter().zip(points) {
+                assert!(moved.abs_diff_eq(transform.transform(point), POINT_TOLERANCE));
+                assert!(moved.abs_diff_eq(transform.transform_scalar(point), POINT_TOLERANCE));
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{Bivector, Rotor, Transform};
+use std::f32::consts::PI;
+
+/// Rotations that cover the awkward cases, shared by the tests of everything built on rotors
+pub(crate) fn rotors() -> Vec<Rotor> {
+    vec![
+        Rotor::IDENTITY,
+        Rotor::rotation_xy(1e-6),
+        Rotor::rotation_xz(0.8),
+        Rotor::rotation_xw(2.0).then(Rotor::rotation_yz(-0.5)),
+        Rotor::rotation_yw(1.0).then(Rotor::rotation_xz(0.3)),
+        // half turns, where the scalar part is zero
+        Rotor::rotation_xy(PI),
+        Rotor::rotation_xy(PI).then(Rotor::rotation_zw(0.4)),
+        // the central inversion
+        Rotor::rotation_xy(PI).then(Rotor::rotation_zw(PI)),
+        Rotor::left_isoclinic(Bivector::rotation_xy(1.0), 0.7),
+        Rotor::right_isoclinic(Bivector::rotation_xw(1.0), -1.3),
+    ]
+}
+
+/// Every component in -1..1 from a fixed seed, so these are rarely anywhere near normalised
+pub(crate) fn arbitrary_transforms(count: usize) -> Vec<Transform> {
+    let mut state = 0x2545_f491_u32;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as f32 / u32::MAX as f32 * 2.0 - 1.0
+    };
+    (0..count)
+        .map(|_| bytemuck::cast::<[f32; 16], Transform>(std::array::from_fn(|_| next())))
+        .collect()
+}
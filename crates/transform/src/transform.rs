@@ -1,11 +1,18 @@
-use crate::{GlobalTransform, Rotor};
+use crate::{
+    DTransform, GlobalTransform, Point4, Rotor, Screw, Vec4,
+    rotor::{bivector_isoclinic_parts, quaternion_log},
+    simd,
+};
 use bevy::{
     ecs::component::Component,
+    math::{Mat4, Quat, Vec3},
     reflect::{Reflect, prelude::ReflectDefault},
 };
 use bytemuck::{Pod, Zeroable};
+use std::ops::{Mul, MulAssign, Neg};
 
 #[derive(Component, Reflect, Debug, Clone, Copy, Zeroable, Pod)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[reflect(Default, Clone)]
 #[require(GlobalTransform)]
 #[repr(C)]
@@ -59,6 +66,38 @@ impl Transform {
         }
     }
 
+    #[must_use]
+    pub const fn from_rotor(rotor: Rotor) -> Self {
+        let Rotor {
+            s,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            e1234,
+        } = rotor;
+        Self {
+            s,
+            e12,
+            e13,
+            e14,
+            e23,
+            e24,
+            e34,
+            e1234,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Rotates by `rotor` and then translates by `translation`
+    #[must_use]
+    pub const fn from_translation_rotor(translation: Vec4, rotor: Rotor) -> Self {
+        let Vec4 { x, y, z, w } = translation;
+        Self::translation(x, y, z, w).then_scalar(Self::from_rotor(rotor))
+    }
+
     #[must_use]
     pub fn rotation_xy(angle: f32) -> Self {
         Self::from(Rotor::rotation_xy(angle))
@@ -91,7 +130,7 @@ impl Transform {
 
     #[must_use]
     pub const fn magnitude_squared(self) -> f32 {
-        self.then(self.inverse()).s
+        self.then_scalar(self.inverse()).s
     }
 
     #[must_use]
@@ -99,9 +138,16 @@ impl Transform {
         self.magnitude_squared().sqrt()
     }
 
+    /// Projects back onto the rigid motions, unlike scaling this also fixes the drift from many `then` calls
+    /// that leaves the e1234 and e0ijk parts inconsistent and makes objects shear
     #[must_use]
     pub fn normalised(self) -> Self {
-        let inverse_magnitude = self.magnitude().recip();
+        let (translation, rotor) = self.to_translation_rotor();
+        Self::from_translation_rotor(translation, rotor.normalized())
+    }
+
+    #[must_use]
+    pub const fn inverse(self) -> Self {
         let Self {
             s,
             e01,
@@ -121,27 +167,27 @@ impl Transform {
             e1234,
         } = self;
         Self {
-            s: s * inverse_magnitude,
-            e01: e01 * inverse_magnitude,
-            e02: e02 * inverse_magnitude,
-            e03: e03 * inverse_magnitude,
-            e04: e04 * inverse_magnitude,
-            e12: e12 * inverse_magnitude,
-            e13: e13 * inverse_magnitude,
-            e14: e14 * inverse_magnitude,
-            e23: e23 * inverse_magnitude,
-            e24: e24 * inverse_magnitude,
-            e34: e34 * inverse_magnitude,
-            e0123: e0123 * inverse_magnitude,
-            e0124: e0124 * inverse_magnitude,
-            e0134: e0134 * inverse_magnitude,
-            e0234: e0234 * inverse_magnitude,
-            e1234: e1234 * inverse_magnitude,
+            s,
+            e01: -e01,
+            e02: -e02,
+            e03: -e03,
+            e04: -e04,
+            e12: -e12,
+            e13: -e13,
+            e14: -e14,
+            e23: -e23,
+            e24: -e24,
+            e34: -e34,
+            e0123,
+            e0124,
+            e0134,
+            e0234,
+            e1234,
         }
     }
 
     #[must_use]
-    pub const fn inverse(self) -> Self {
+    pub(crate) const fn negated(self) -> Self {
         let Self {
             s,
             e01,
@@ -161,7 +207,7 @@ impl Transform {
             e1234,
         } = self;
         Self {
-            s,
+            s: -s,
             e01: -e01,
             e02: -e02,
             e03: -e03,
@@ -172,16 +218,42 @@ impl Transform {
             e23: -e23,
             e24: -e24,
             e34: -e34,
-            e0123,
-            e0124,
-            e0134,
-            e0234,
-            e1234,
+            e0123: -e0123,
+            e0124: -e0124,
+            e0134: -e0134,
+            e0234: -e0234,
+            e1234: -e1234,
         }
     }
 
+    /// Whether every component is within `max_abs_diff` of the matching component of `other`
     #[must_use]
-    pub const fn then(self, other: Self) -> Self {
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f32) -> bool {
+        let a: [f32; 16] = bytemuck::cast(self);
+        let b: [f32; 16] = bytemuck::cast(other);
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() <= max_abs_diff)
+    }
+
+    /// Like [`Self::abs_diff_eq`] but also true when `other` is close to `-self`, which is the same transform
+    #[must_use]
+    pub fn approx_eq(self, other: Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(other, max_abs_diff) || self.abs_diff_eq(-other, max_abs_diff)
+    }
+
+    #[must_use]
+    pub fn as_dtransform(self) -> DTransform {
+        bytemuck::cast(bytemuck::cast::<_, [f32; 16]>(self).map(f64::from))
+    }
+
+    #[must_use]
+    pub fn then(self, other: Self) -> Self {
+        simd::transform_then(self, other)
+    }
+
+    /// [`Self::then`] without SIMD, which also works in const contexts, both agree up to rounding
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn then_scalar(self, other: Self) -> Self {
         let Self {
             s: a1,
             e01: b1,
@@ -358,8 +430,30 @@ impl Transform {
         }
     }
 
+    /// Only exact for normalised transforms, which is what [`crate::TransformPlugin`] keeps them as
+    #[must_use]
+    pub fn transform(self, point: Point4) -> Point4 {
+        simd::transform_point(self, point)
+    }
+
+    /// Transforms every point in place, the motor is turned into a matrix once
+    /// so this is a lot cheaper than calling [`Self::transform`] on each of them,
+    /// like that it is only exact for normalised transforms
+    pub fn transform_points(self, points: &mut [Point4]) {
+        let (translation, rotor) = self.to_translation_rotor();
+        let matrix = rotor.to_matrix4();
+        let translation = bevy::math::Vec4::from(translation);
+        for point in points {
+            *point = (matrix * bevy::math::Vec4::from_array((*point).into()) + translation)
+                .to_array()
+                .into();
+        }
+    }
+
+    /// [`Self::transform`] without SIMD, which also works in const contexts, both agree up to rounding
+    #[doc(hidden)]
     #[must_use]
-    pub const fn transform(self, point: (f32, f32, f32, f32)) -> (f32, f32, f32, f32) {
+    pub const fn transform_scalar(self, point: Point4) -> Point4 {
         let Self {
             s: a,
             e01: b,
@@ -378,7 +472,12 @@ impl Transform {
             e0234: p,
             e1234: q,
         } = self;
-        let (p3, p2, p1, p0) = point;
+        let Point4 {
+            x: p3,
+            y: p2,
+            z: p1,
+            w: p0,
+        } = point;
         let ap2 = a * p2;
         let gp3 = g * p3;
         let jp1 = j * p1;
@@ -428,7 +527,7 @@ impl Transform {
                     + h * s2
                     + g * s0),
         ];
-        (x, y, z, w)
+        Point4 { x, y, z, w }
     }
 
     #[must_use]
@@ -462,6 +561,197 @@ impl Transform {
             e1234,
         }
     }
+
+    #[must_use]
+    pub const fn translation_part(self) -> Vec4 {
+        // removing the rotation leaves a pure translator,
+        // dividing by its scalar part keeps this correct for transforms that arent normalised
+        let translator = self.then_scalar(Self::from_rotor(self.rotor_part().inverse()));
+        let scale = 2.0 / translator.s;
+        Vec4 {
+            x: translator.e01 * scale,
+            y: translator.e02 * -scale,
+            z: translator.e03 * scale,
+            w: translator.e04 * -scale,
+        }
+    }
+
+    #[must_use]
+    pub const fn to_translation_rotor(self) -> (Vec4, Rotor) {
+        (self.translation_part(), self.rotor_part())
+    }
+
+    #[must_use]
+    pub const fn with_translation(self, translation: Vec4) -> Self {
+        Self::from_translation_rotor(translation, self.rotor_part())
+    }
+
+    #[must_use]
+    pub const fn with_rotor(self, rotor: Rotor) -> Self {
+        Self::from_translation_rotor(self.translation_part(), rotor)
+    }
+
+    /// Places a camera at `eye` with its forward (x) axis pointing at `target`,
+    /// its up (y) and over (w) axes as close to `up` and `over` as possible, and its right (z) axis completing the frame
+    #[must_use]
+    pub fn look_at(eye: Point4, target: Point4, up: Vec4, over: Vec4) -> Self {
+        // removes the parts along the axes found so far, falling back to any other direction if nothing is left
+        let orthonormalise = |direction: Vec4, axes: &[Vec4]| {
+            [direction.normalised_or_zero()]
+                .into_iter()
+                .chain(Vec4::AXES)
+                .map(|direction| {
+                    axes.iter().fold(direction, |direction, &axis| {
+                        direction - axis * direction.dot(axis)
+                    })
+                })
+                .find(|direction| direction.length_squared() > 1e-6)
+                .unwrap()
+                .normalised()
+        };
+
+        let forward = orthonormalise(target - eye, &[]);
+        let up = orthonormalise(up, &[forward]);
+        let over = orthonormalise(over, &[forward, up]);
+        // the right axis that gives the frame a determinant of 1
+        let right = Vec4::cross(forward, over, up);
+
+        Self::from_translation_rotor(
+            eye.to_vector(),
+            Rotor::from_matrix4(Mat4::from_cols(
+                forward.into(),
+                up.into(),
+                right.into(),
+                over.into(),
+            )),
+        )
+    }
+
+    /// A homogeneous matrix in column major order, so `matrix[4]` is the translation
+    #[must_use]
+    pub const fn to_matrix5(self) -> [[f32; 5]; 5] {
+        let (
+            Vec4 {
+                x: tx,
+                y: ty,
+                z: tz,
+                w: tw,
+            },
+            rotor,
+        ) = self.to_translation_rotor();
+        let [x, y, z, w] = rotor.to_matrix4().to_cols_array_2d();
+        [
+            [x[0], x[1], x[2], x[3], 0.0],
+            [y[0], y[1], y[2], y[3], 0.0],
+            [z[0], z[1], z[2], z[3], 0.0],
+            [w[0], w[1], w[2], w[3], 0.0],
+            [tx, ty, tz, tw, 1.0],
+        ]
+    }
+
+    /// Finds the transform closest to the homogeneous column major `matrix`, so its rotation doesnt need to be exactly orthonormal
+    #[must_use]
+    pub fn from_matrix5(matrix: [[f32; 5]; 5]) -> Self {
+        let [x, y, z, w, [tx, ty, tz, tw, scale]] = matrix;
+        let rotor = Rotor::from_matrix4(Mat4::from_cols_array_2d(&[
+            [x[0], x[1], x[2], x[3]],
+            [y[0], y[1], y[2], y[3]],
+            [z[0], z[1], z[2], z[3]],
+            [w[0], w[1], w[2], w[3]],
+        ]));
+        Self::from_translation_rotor(Vec4::new(tx, ty, tz, tw) / scale, rotor)
+    }
+
+    #[must_use]
+    pub fn exp(screw: Screw) -> Self {
+        let bivector = screw.bivector_part();
+        let (left, right) = bivector_isoclinic_parts(bivector);
+        let translation = averaged_rotation(left, right, screw.translation_part(), false);
+        Self::from_translation_rotor(translation, Rotor::exp(bivector))
+    }
+
+    /// The principal logarithm, `Transform::exp(transform.log())` gives back `transform`
+    #[must_use]
+    pub fn log(self) -> Screw {
+        let (translation, rotor) = self.to_translation_rotor();
+        let (left, right) = rotor.to_quaternion_pair();
+        let (left, right) = (quaternion_log(left), quaternion_log(right));
+        let Vec4 { x, y, z, w } = averaged_rotation(left, right, translation, true);
+        let Screw {
+            e01, e02, e03, e04, ..
+        } = Screw::translation(x, y, z, w);
+        Screw {
+            e01,
+            e02,
+            e03,
+            e04,
+            ..Screw::from_bivector(rotor.log())
+        }
+    }
+
+    /// Interpolates the translation linearly and the rotation with [`Rotor::slerp`]
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let (translation1, rotor1) = self.to_translation_rotor();
+        let (translation2, rotor2) = other.to_translation_rotor();
+        Self::from_translation_rotor(translation1.lerp(translation2, t), rotor1.slerp(rotor2, t))
+    }
+
+    /// Interpolates along the shortest screw motion, moving at a constant speed
+    #[must_use]
+    pub fn slerp(self, other: Self, t: f32) -> Self {
+        let mut difference = self.inverse().then(other);
+        // the negated difference is the same transform, so take whichever is the shorter path
+        if difference.s < 0.0 {
+            difference = difference.negated();
+        }
+        self.then(Self::exp(difference.log().scaled(t)))
+    }
+}
+
+/// Averages `direction` rotated along the whole path from the identity to the rotation with isoclinic logarithms `left` and `right`,
+/// which is how far a screw motion translates, with `inverse` set this finds the direction from that translation instead
+fn averaged_rotation(left: Vec3, right: Vec3, direction: Vec4, inverse: bool) -> Vec4 {
+    let left_angle = left.length();
+    let right_angle = right.length();
+    // the axis doesnt matter when the angle is zero
+    let left_axis = Quat::from_vec4(left.try_normalize().unwrap_or(Vec3::X).extend(0.0));
+    let right_axis = Quat::from_vec4(right.try_normalize().unwrap_or(Vec3::X).extend(0.0));
+
+    let Vec4 { x, y, z, w } = direction;
+    let direction = Quat::from_xyzw(y, z, w, x);
+
+    // split the direction between the two invariant planes,
+    // which rotate by the difference and the sum of the isoclinic angles
+    let swapped = left_axis * direction * right_axis;
+    let result = averaged_plane_rotation(
+        (direction - swapped) * 0.5,
+        left_axis,
+        left_angle - right_angle,
+        inverse,
+    ) + averaged_plane_rotation(
+        (direction + swapped) * 0.5,
+        left_axis,
+        left_angle + right_angle,
+        inverse,
+    );
+    Vec4::new(result.w, result.x, result.y, result.z)
+}
+
+fn averaged_plane_rotation(direction: Quat, axis: Quat, angle: f32, inverse: bool) -> Quat {
+    let full_sinc = sinc(angle);
+    let half_sinc = sinc(angle * 0.5);
+    // (1 - cos(angle)) / angle
+    let cosc = (angle * 0.5).sin() * half_sinc;
+    if inverse {
+        (direction * full_sinc - axis * direction * cosc) / (half_sinc * half_sinc)
+    } else {
+        direction * full_sinc + axis * direction * cosc
+    }
+}
+
+fn sinc(x: f32) -> f32 {
+    if x != 0.0 { x.sin() / x } else { 1.0 }
 }
 
 impl Default for Transform {
@@ -472,26 +762,246 @@ impl Default for Transform {
 
 impl From<Rotor> for Transform {
     fn from(rotor: Rotor) -> Self {
-        let Rotor {
-            s,
-            e12,
-            e13,
-            e14,
-            e23,
-            e24,
-            e34,
-            e1234,
-        } = rotor;
-        Self {
-            s,
-            e12,
-            e13,
-            e14,
-            e23,
-            e24,
-            e34,
-            e1234,
-            ..Self::IDENTITY
+        Self::from_rotor(rotor)
+    }
+}
+
+/// `a * b` is `a.then(b)`, so `(a * b) * point == a * (b * point)`
+impl Mul for Transform {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.then(other)
+    }
+}
+
+impl MulAssign for Transform {
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.then(other);
+    }
+}
+
+/// Directions are only rotated, the translation doesnt affect them
+impl Mul<Vec4> for Transform {
+    type Output = Vec4;
+
+    fn mul(self, direction: Vec4) -> Vec4 {
+        self.rotor_part().rotate(direction)
+    }
+}
+
+impl Mul<Point4> for Transform {
+    type Output = Point4;
+
+    fn mul(self, point: Point4) -> Point4 {
+        self.transform(point)
+    }
+}
+
+/// The negated transform moves every point the same way
+impl Neg for Transform {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.negated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::rotors;
+
+    const TOLERANCE: f32 = 1e-5;
+
+    #[test]
+    fn translation_rotor_round_trip() {
+        let translation = Vec4::new(1.0, -2.0, 3.5, 0.25);
+        for rotor in rotors() {
+            let (out_translation, out_rotor) =
+                Transform::from_translation_rotor(translation, rotor).to_translation_rotor();
+            assert!(out_translation.abs_diff_eq(translation, TOLERANCE));
+            assert!(out_rotor.abs_diff_eq(rotor, TOLERANCE));
+        }
+    }
+
+    #[test]
+    fn with_translation_keeps_the_rotor() {
+        let translation = Vec4::new(-4.0, 0.5, 2.0, 7.0);
+        for rotor in rotors() {
+            let transform = Transform::from_translation_rotor(Vec4::new(1.0, 1.0, 1.0, 1.0), rotor)
+                .with_translation(translation);
+            assert!(
+                transform
+                    .translation_part()
+                    .abs_diff_eq(translation, TOLERANCE)
+            );
+            assert!(transform.rotor_part().abs_diff_eq(rotor, TOLERANCE));
+            assert!(
+                transform
+                    .with_translation(Vec4::ZERO)
+                    .abs_diff_eq(Transform::from_rotor(rotor), TOLERANCE)
+            );
+        }
+    }
+
+    #[test]
+    fn matrix5_round_trip() {
+        for (transform, _) in pairs() {
+            let matrix = transform.to_matrix5();
+            assert!(
+                Transform::from_matrix5(matrix).approx_eq(transform, 1e-4),
+                "{transform:?}"
+            );
+            // a homogeneous scale and slightly skewed rotation give back the same transform
+            let mut skewed = matrix.map(|column| column.map(|x| x * 2.0));
+            skewed[0][1] += 1e-3;
+            let out = Transform::from_matrix5(skewed);
+            assert!(out.approx_eq(transform, 1e-3), "{transform:?}");
+            let rotation = out.rotor_part().to_matrix4();
+            assert!((rotation.transpose() * rotation).abs_diff_eq(Mat4::IDENTITY, 1e-4));
+        }
+    }
+
+    #[test]
+    fn look_at_points_forward_and_up() {
+        let eye = Point4::new(1.0, 2.0, -3.0, 0.5);
+        for (target, up, over) in [
+            (Point4::new(5.0, 2.0, -3.0, 0.5), Vec4::Y, Vec4::W),
+            (Point4::new(1.0, 2.0, 4.0, 0.5), Vec4::Y, Vec4::W),
+            (
+                Point4::new(-2.0, 3.0, 1.0, 6.0),
+                Vec4::new(0.2, 1.0, 0.1, 0.0),
+                Vec4::W,
+            ),
+            // `up` along the forward axis falls back to some other direction
+            (Point4::new(1.0, 9.0, -3.0, 0.5), Vec4::Y, Vec4::W),
+        ] {
+            let transform = Transform::look_at(eye, target, up, over);
+            let rotor = transform.rotor_part();
+            let forward = (target - eye).normalised();
+            assert!(
+                transform
+                    .translation_part()
+                    .abs_diff_eq(eye.to_vector(), TOLERANCE)
+            );
+            assert!(
+                rotor.rotate(Vec4::X).abs_diff_eq(forward, 1e-4),
+                "{target:?}"
+            );
+            // up is as close to `up` as it can be while staying orthogonal to forward
+            let expected_up = up - forward * up.dot(forward);
+            if expected_up.length_squared() > 1e-6 {
+                assert!(
+                    rotor
+                        .rotate(Vec4::Y)
+                        .abs_diff_eq(expected_up.normalised(), 1e-4),
+                    "{target:?}"
+                );
+            }
+            assert!(rotor.rotate(Vec4::Y).dot(forward).abs() < 1e-4);
+        }
+    }
+
+    /// Each fixture rotor moved somewhere, paired with the next one moved somewhere else
+    fn pairs() -> Vec<(Transform, Transform)> {
+        let transforms: Vec<Transform> = rotors()
+            .into_iter()
+            .enumerate()
+            .map(|(i, rotor)| {
+                let translation = Vec4::new(i as f32, 1.0 - i as f32, 0.5, -0.25 * i as f32);
+                Transform::from_translation_rotor(translation, rotor)
+            })
+            .collect();
+        let next = transforms.iter().copied().cycle().skip(1);
+        transforms.iter().copied().zip(next).collect()
+    }
+
+    #[test]
+    fn operators_compose_like_applying_in_turn() {
+        let direction = Vec4::new(0.3, -1.2, 2.0, 0.7);
+        let point = Point4::new(1.0, -2.0, 3.5, 0.25);
+        for (a, b) in pairs() {
+            assert!(((a * b) * direction).abs_diff_eq(a * (b * direction), 1e-4));
+            assert!(((a * b) * point).abs_diff_eq(a * (b * point), 1e-4));
+            // the negation is the same transform
+            assert!((-a * direction).abs_diff_eq(a * direction, 1e-4));
+            assert!((-a * point).abs_diff_eq(a * point, 1e-4));
+            assert!((-a).approx_eq(a, 0.0));
+            let mut composed = a;
+            composed *= b;
+            assert!(composed.abs_diff_eq(a * b, 0.0));
+            assert!((a * b).abs_diff_eq(a.then(b), 0.0));
+        }
+    }
+
+    #[test]
+    fn interpolation_endpoints() {
+        for (a, b) in pairs() {
+            assert!(a.lerp(b, 0.0).approx_eq(a, 1e-4), "{a:?} {b:?}");
+            assert!(a.lerp(b, 1.0).approx_eq(b, 1e-4), "{a:?} {b:?}");
+            assert!(a.slerp(b, 0.0).approx_eq(a, 1e-4), "{a:?} {b:?}");
+            assert!(a.slerp(b, 1.0).approx_eq(b, 1e-4), "{a:?} {b:?}");
+        }
+    }
+
+    #[test]
+    fn interpolation_takes_the_shorter_path() {
+        let start = Transform::IDENTITY;
+        let end = Transform::from_translation_rotor(Vec4::X, Rotor::rotation_yz(1.0));
+        let halfway = Rotor::rotation_yz(0.5);
+        // `-end` is the same transform, the long way round would turn by pi + 0.5
+        for end in [end, -end] {
+            let lerped = start.lerp(end, 0.5);
+            assert!(lerped.rotor_part().approx_eq(halfway, 1e-4));
+            assert!(lerped.translation_part().abs_diff_eq(Vec4::X * 0.5, 1e-4));
+            assert!(start.slerp(end, 0.5).rotor_part().approx_eq(halfway, 1e-4));
+        }
+        for (a, b) in pairs() {
+            // exactly half a turn apart both ways are as short, so either answer is fine
+            if a.rotor_part().inverse().then(b.rotor_part()).s.abs() < 0.1 {
+                continue;
+            }
+            for t in [0.25, 0.5, 0.75] {
+                assert!(
+                    a.lerp(-b, t).approx_eq(a.lerp(b, t), 1e-4),
+                    "{a:?} {b:?} {t}"
+                );
+                assert!(
+                    a.slerp(-b, t).approx_eq(a.slerp(b, t), 1e-4),
+                    "{a:?} {b:?} {t}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn slerp_moves_at_a_constant_speed() {
+        const STEPS: usize = 8;
+        for (a, b) in pairs() {
+            let points: Vec<Transform> = (0..=STEPS)
+                .map(|i| a.slerp(b, i as f32 / STEPS as f32))
+                .collect();
+            let first_step = points[0].inverse().then(points[1]);
+            for window in points.windows(2) {
+                let step = window[0].inverse().then(window[1]);
+                assert!(step.approx_eq(first_step, 1e-4), "{a:?} {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn with_rotor_keeps_the_translation() {
+        let translation = Vec4::new(3.0, -1.0, 0.0, 2.0);
+        let transform = Transform::from_translation_rotor(translation, Rotor::rotation_xw(0.7));
+        for rotor in rotors() {
+            let transform = transform.with_rotor(rotor);
+            assert!(
+                transform
+                    .translation_part()
+                    .abs_diff_eq(translation, TOLERANCE)
+            );
+            assert!(transform.rotor_part().abs_diff_eq(rotor, TOLERANCE));
         }
     }
 }
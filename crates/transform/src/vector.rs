@@ -0,0 +1,310 @@
+use crate::{Bivector, DVec4};
+use bevy::reflect::{Reflect, prelude::ReflectDefault};
+use bytemuck::{Pod, Zeroable};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A direction or displacement in 4D space
+#[derive(Reflect, Debug, Default, Clone, Copy, PartialEq, Zeroable, Pod)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[repr(C)]
+pub struct Vec4 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Vec4 {
+    pub const ZERO: Self = Self::splat(0.0);
+    pub const ONE: Self = Self::splat(1.0);
+    pub const X: Self = Self::new(1.0, 0.0, 0.0, 0.0);
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0, 0.0);
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0, 0.0);
+    pub const W: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+    pub const AXES: [Self; 4] = [Self::X, Self::Y, Self::Z, Self::W];
+
+    #[must_use]
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    #[must_use]
+    pub const fn splat(value: f32) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    #[must_use]
+    pub const fn dot(self, other: Self) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// The 4D cross product, a vector perpendicular to all three inputs,
+    /// `Vec4::cross(a, b, c).dot(d)` is the determinant of the matrix with columns `a`, `b`, `c` and `d`
+    #[must_use]
+    pub const fn cross(a: Self, b: Self, c: Self) -> Self {
+        let xy = a.x * b.y - a.y * b.x;
+        let xz = a.x * b.z - a.z * b.x;
+        let xw = a.x * b.w - a.w * b.x;
+        let yz = a.y * b.z - a.z * b.y;
+        let yw = a.y * b.w - a.w * b.y;
+        let zw = a.z * b.w - a.w * b.z;
+        Self {
+            x: -(yz * c.w - yw * c.z + zw * c.y),
+            y: xz * c.w - xw * c.z + zw * c.x,
+            z: -(xy * c.w - xw * c.y + yw * c.x),
+            w: xy * c.z - xz * c.y + yz * c.x,
+        }
+    }
+
+    /// The oriented plane spanned by the two directions, turning from `self` towards `other`
+    #[must_use]
+    pub const fn wedge(self, other: Self) -> Bivector {
+        Bivector {
+            e12: self.x * other.y - self.y * other.x,
+            e13: self.z * other.x - self.x * other.z,
+            e14: self.x * other.w - self.w * other.x,
+            e23: self.y * other.z - self.z * other.y,
+            e24: self.w * other.y - self.y * other.w,
+            e34: self.z * other.w - self.w * other.z,
+        }
+    }
+
+    #[must_use]
+    pub const fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    #[must_use]
+    pub fn length(self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    #[must_use]
+    pub fn normalised(self) -> Self {
+        self / self.length()
+    }
+
+    /// Returns [`None`] when the vector is too close to zero to have a direction
+    #[must_use]
+    pub fn try_normalised(self) -> Option<Self> {
+        let inverse_length = self.length().recip();
+        (inverse_length.is_finite() && inverse_length > 0.0).then(|| self * inverse_length)
+    }
+
+    #[must_use]
+    pub fn normalised_or_zero(self) -> Self {
+        self.try_normalised().unwrap_or(Self::ZERO)
+    }
+
+    /// The angle between the two directions, from 0 to pi
+    #[must_use]
+    pub fn angle_between(self, other: Self) -> f32 {
+        let cos = self.dot(other) / (self.length_squared() * other.length_squared()).sqrt();
+        cos.clamp(-1.0, 1.0).acos()
+    }
+
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+
+    #[must_use]
+    pub const fn to_array(self) -> [f32; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    /// Whether every component is within `max_abs_diff` of the matching component of `other`
+    #[must_use]
+    pub fn abs_diff_eq(self, other: Self, max_abs_diff: f32) -> bool {
+        let (a, b) = (self.to_array(), other.to_array());
+        a.iter().zip(b).all(|(a, b)| (a - b).abs() <= max_abs_diff)
+    }
+
+    #[must_use]
+    pub const fn as_dvec4(self) -> DVec4 {
+        DVec4::new(self.x as f64, self.y as f64, self.z as f64, self.w as f64)
+    }
+}
+
+impl Add for Vec4 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            self.x + other.x,
+            self.y + other.y,
+            self.z + other.z,
+            self.w + other.w,
+        )
+    }
+}
+
+impl AddAssign for Vec4 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec4 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(
+            self.x - other.x,
+            self.y - other.y,
+            self.z - other.z,
+            self.w - other.w,
+        )
+    }
+}
+
+impl SubAssign for Vec4 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<f32> for Vec4 {
+    type Output = Self;
+
+    fn mul(self, scale: f32) -> Self {
+        Self::new(
+            self.x * scale,
+            self.y * scale,
+            self.z * scale,
+            self.w * scale,
+        )
+    }
+}
+
+impl Mul<Vec4> for f32 {
+    type Output = Vec4;
+
+    fn mul(self, vector: Vec4) -> Vec4 {
+        vector * self
+    }
+}
+
+impl MulAssign<f32> for Vec4 {
+    fn mul_assign(&mut self, scale: f32) {
+        *self = *self * scale;
+    }
+}
+
+impl Div<f32> for Vec4 {
+    type Output = Self;
+
+    fn div(self, scale: f32) -> Self {
+        Self::new(
+            self.x / scale,
+            self.y / scale,
+            self.z / scale,
+            self.w / scale,
+        )
+    }
+}
+
+impl DivAssign<f32> for Vec4 {
+    fn div_assign(&mut self, scale: f32) {
+        *self = *self / scale;
+    }
+}
+
+impl Neg for Vec4 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Vec4 {
+    fn from((x, y, z, w): (f32, f32, f32, f32)) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+impl From<Vec4> for (f32, f32, f32, f32) {
+    fn from(Vec4 { x, y, z, w }: Vec4) -> Self {
+        (x, y, z, w)
+    }
+}
+
+impl From<[f32; 4]> for Vec4 {
+    fn from([x, y, z, w]: [f32; 4]) -> Self {
+        Self::new(x, y, z, w)
+    }
+}
+
+impl From<Vec4> for [f32; 4] {
+    fn from(vector: Vec4) -> Self {
+        vector.to_array()
+    }
+}
+
+impl From<bevy::math::Vec4> for Vec4 {
+    fn from(vector: bevy::math::Vec4) -> Self {
+        Self::from(vector.to_array())
+    }
+}
+
+impl From<Vec4> for bevy::math::Vec4 {
+    fn from(vector: Vec4) -> Self {
+        Self::from_array(vector.to_array())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Rotor;
+
+    const TOLERANCE: f32 = 1e-5;
+
+    #[test]
+    fn wedge_matches_the_rotation_planes() {
+        let planes = [
+            (Vec4::X, Vec4::Y, Bivector::rotation_xy(2.0)),
+            (Vec4::X, Vec4::Z, Bivector::rotation_xz(2.0)),
+            (Vec4::X, Vec4::W, Bivector::rotation_xw(2.0)),
+            (Vec4::Y, Vec4::Z, Bivector::rotation_yz(2.0)),
+            (Vec4::Y, Vec4::W, Bivector::rotation_yw(2.0)),
+            (Vec4::Z, Vec4::W, Bivector::rotation_zw(2.0)),
+        ];
+        for (from, towards, plane) in planes {
+            assert!(from.wedge(towards).abs_diff_eq(plane, TOLERANCE));
+            assert!(
+                towards
+                    .wedge(from)
+                    .abs_diff_eq(plane.scaled(-1.0), TOLERANCE)
+            );
+            // a small rotation in the plane turns `from` towards `towards`
+            let turned = Rotor::exp(from.wedge(towards).scaled(0.1)).rotate(from);
+            assert!(turned.dot(towards) > 0.0, "{from:?} {towards:?}");
+        }
+    }
+
+    #[test]
+    fn cross_is_orthogonal_and_right_handed() {
+        assert!(Vec4::cross(Vec4::X, Vec4::Y, Vec4::Z).abs_diff_eq(Vec4::W, TOLERANCE));
+
+        let a = Vec4::new(0.3, -1.2, 2.0, 0.7);
+        let b = Vec4::new(-1.0, 0.5, 0.0, 4.0);
+        let c = Vec4::new(2.0, 1.0, -0.5, 0.25);
+        let cross = Vec4::cross(a, b, c);
+        for input in [a, b, c] {
+            assert!(cross.dot(input).abs() < 1e-4);
+        }
+        // swapping any two inputs flips the orientation
+        assert!(Vec4::cross(b, a, c).abs_diff_eq(-cross, TOLERANCE));
+        assert!(Vec4::cross(a, c, b).abs_diff_eq(-cross, TOLERANCE));
+        assert!(Vec4::cross(b, c, a).abs_diff_eq(cross, TOLERANCE));
+
+        // the determinant of the columns a, b, c and d
+        let d = Vec4::new(1.0, 1.0, 1.0, -1.0);
+        let determinant =
+            bevy::math::Mat4::from_cols(a.into(), b.into(), c.into(), d.into()).determinant();
+        assert!((cross.dot(d) - determinant).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{Bivector, Rotor, Transform, Vec4};
+use bevy::{
+    ecs::{
+        component::Component,
+        query::{Or, With},
+        system::{Query, Res},
+    },
+    reflect::{Reflect, prelude::ReflectDefault},
+    time::Time,
+};
+
+/// How fast an entity moves through the space of its parent, in units per second,
+/// it keeps going the same way whether or not it is turning
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[require(Transform)]
+pub struct Velocity4(pub Vec4);
+
+/// How fast an entity spins around its own origin, `AngularVelocity4(Bivector::rotation_xy(rate))`
+/// turns it `rate` radians per second within its local xy plane
+#[derive(Component, Reflect, Default, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Default, Clone)]
+#[require(Transform)]
+pub struct AngularVelocity4(pub Bivector);
+
+/// Moves entities by their velocities over the last timestep, the velocities are constant within a step
+/// and the translation and rotation dont affect each other, so this is exact no matter how large the step is
+pub(crate) fn integrate_velocities(
+    time: Res<Time>,
+    mut bodies: Query<
+        (
+            &mut Transform,
+            Option<&Velocity4>,
+            Option<&AngularVelocity4>,
+        ),
+        Or<(With<Velocity4>, With<AngularVelocity4>)>,
+    >,
+) {
+    let delta = time.delta_secs();
+    bodies
+        .par_iter_mut()
+        .for_each(|(mut transform, velocity, angular_velocity)| {
+            let velocity = velocity.map_or(Vec4::ZERO, |velocity| velocity.0);
+            let angular_velocity =
+                angular_velocity.map_or(Bivector::ZERO, |angular_velocity| angular_velocity.0);
+            // resting bodies would otherwise mark their whole tree for propagation every step
+            if velocity == Vec4::ZERO && angular_velocity.magnitude_squared() == 0.0 {
+                return;
+            }
+            let Vec4 { x, y, z, w } = velocity * delta;
+            // translating before the transform moves it in the space of the parent,
+            // rotating after it turns it around its own origin
+            *transform = Transform::translation(x, y, z, w)
+                .then(*transform)
+                .then(Rotor::exp(angular_velocity.scaled(delta)).into());
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TransformPlugin;
+    use bevy::{
+        app::App,
+        time::{Fixed, TimePlugin, TimeUpdateStrategy},
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn moves_straight_while_spinning() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, TransformPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                10,
+            )));
+        let start = Transform::translation(1.0, 0.0, 0.0, 0.0).then(Transform::rotation_zw(0.3));
+        let body = app
+            .world_mut()
+            .spawn((
+                start,
+                Velocity4(Vec4::new(0.0, 2.0, 0.0, 1.0)),
+                AngularVelocity4(Bivector::rotation_xy(1.5)),
+            ))
+            .id();
+        for _ in 0..50 {
+            app.update();
+        }
+
+        let elapsed = app.world().resource::<Time<Fixed>>().elapsed_secs();
+        assert!(elapsed > 0.0);
+        let transform = *app.world().get::<Transform>(body).unwrap();
+        let expected = Transform::from_translation_rotor(
+            Vec4::new(1.0, 2.0 * elapsed, 0.0, elapsed),
+            start.rotor_part().then(Rotor::rotation_xy(1.5 * elapsed)),
+        );
+        assert!(
+            transform.approx_eq(expected, 1e-4),
+            "{transform:?} {expected:?}"
+        );
+    }
+}
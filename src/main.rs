@@ -1,3 +1,4 @@
+use animation::AnimationPlugin;
 use bevy::{
     a11y::AccessibilityPlugin,
     app::{App, AppExit, PanicHandlerPlugin, Startup, TaskPoolPlugin, Update},
@@ -5,14 +6,10 @@ use bevy::{
     diagnostic::{
         DiagnosticsPlugin, DiagnosticsStore, FrameCountPlugin, FrameTimeDiagnosticsPlugin,
     },
-    ecs::{
-        component::Component,
-        query::With,
-        system::{Commands, Query, Res},
-    },
+    ecs::system::{Commands, Res},
     input::InputPlugin,
     log::{LogPlugin, info},
-    time::{Time, TimePlugin},
+    time::TimePlugin,
     window::WindowPlugin,
     winit::WinitPlugin,
 };
@@ -21,7 +18,7 @@ use render::{
     RenderPlugin,
     ray_tracing::{Camera, Color, HyperSphere, MainCamera, Material},
 };
-use transform::{Rotor, Transform, TransformPlugin};
+use transform::{GridCell, Orbit4, Point4, Rotor, Transform, TransformPlugin, Vec4};
 
 mod movement_control;
 
@@ -43,10 +40,11 @@ fn main() -> AppExit {
         AssetPlugin::default(),
         <WinitPlugin>::default(),
         TransformPlugin,
+        AnimationPlugin,
         RenderPlugin,
     ))
     .add_systems(Startup, setup)
-    .add_systems(Update, (handle_cursor_locking, movement_controls, orbit));
+    .add_systems(Update, (handle_cursor_locking, movement_controls));
 
     if PRINT_FPS {
         app.add_plugins(FrameTimeDiagnosticsPlugin::default())
@@ -56,15 +54,14 @@ fn main() -> AppExit {
     app.run()
 }
 
-#[derive(Component)]
-struct Orbit;
-
 fn setup(mut commands: Commands) {
     commands.spawn((
         MovementControl {
             main_transform: Transform::translation(-3.0, 0.0, 0.0, 0.0),
             xy_rotation: Rotor::IDENTITY,
+            grid_cell: GridCell::ZERO,
         },
+        GridCell::ZERO,
         Camera::default(),
         MainCamera,
     ));
@@ -89,17 +86,15 @@ fn setup(mut commands: Commands) {
             },
         },
         HyperSphere { radius: 0.3 },
-        Orbit,
+        Orbit4 {
+            center: Point4::ORIGIN,
+            plane: [Vec4::Z, Vec4::X],
+            radius: 3.0,
+            period: 4.0,
+        },
     ));
 }
 
-fn orbit(time: Res<Time>, mut transforms: Query<&mut Transform, With<Orbit>>) {
-    transforms.par_iter_mut().for_each(|mut transform| {
-        let (sin, cos) = (time.elapsed_secs() * core::f32::consts::TAU * 0.25).sin_cos();
-        *transform = Transform::translation(sin * 3.0, 0.0, cos * 3.0, 0.0);
-    });
-}
-
 fn print_diagnostics(d: Res<DiagnosticsStore>) {
     if let Some(fps) = d.get_measurement(&FrameTimeDiagnosticsPlugin::FPS) {
         info!("FPS: {}", fps.value);
@@ -14,13 +14,16 @@ use bevy::{
     time::Time,
     window::{CursorGrabMode, PrimaryWindow, Window},
 };
-use transform::{Rotor, Transform};
+use transform::{GridCell, GridSettings, Rotor, Transform, Vec4};
 
 #[derive(Component, Default)]
 #[require(Transform)]
 pub struct MovementControl {
+    /// Relative to the centre of `grid_cell`
     pub main_transform: Transform,
     pub xy_rotation: Rotor,
+    /// The cell `main_transform` is relative to, it follows the [`GridCell`] of the entity when that gets recentred
+    pub grid_cell: GridCell,
 }
 
 pub fn handle_cursor_locking(
@@ -47,15 +50,31 @@ pub fn handle_cursor_locking(
 
 pub fn movement_controls(
     time: Res<Time>,
+    grid_settings: Res<GridSettings>,
     keys: Res<ButtonInput<KeyCode>>,
     mouse_buttons: Res<ButtonInput<MouseButton>>,
     mut mouse: EventReader<MouseMotion>,
-    mut transform: Query<(&mut Transform, &mut MovementControl)>,
+    mut transform: Query<(&mut Transform, &mut MovementControl, Option<&GridCell>)>,
 ) {
-    let (mut out_transform, mut movement_control) = transform
+    let (mut out_transform, mut movement_control, grid_cell) = transform
         .single_mut()
         .expect("there should only be one entity with MovementControl");
 
+    // the transform was moved into another cell, so move main_transform the same way
+    // rather than undoing that the next time it is written back
+    if let Some(&grid_cell) = grid_cell
+        && grid_cell != movement_control.grid_cell
+    {
+        let shift = movement_control
+            .grid_cell
+            .offset_from(grid_cell, grid_settings.cell_size)
+            .as_vec4();
+        let main_transform = movement_control.main_transform;
+        movement_control.main_transform =
+            main_transform.with_translation(main_transform.translation_part() + shift);
+        movement_control.grid_cell = grid_cell;
+    }
+
     let mut moved_or_rotated = false;
 
     {
@@ -123,20 +142,27 @@ pub fn movement_controls(
             w -= 1.0;
         }
         if x != 0.0 || y != 0.0 || z != 0.0 || w != 0.0 {
+            // the keys move along the axes the camera is facing
+            let (translation, rotor) = movement_control.main_transform.to_translation_rotor();
+            let step = rotor.rotate(Vec4::new(x, y, z, w) * (MOVEMENT_SPEED * dt));
             movement_control.main_transform =
-                movement_control.main_transform.then(Transform::translation(
-                    x * MOVEMENT_SPEED * dt,
-                    y * MOVEMENT_SPEED * dt,
-                    z * MOVEMENT_SPEED * dt,
-                    w * MOVEMENT_SPEED * dt,
-                ));
+                Transform::from_translation_rotor(translation + step, rotor);
             moved_or_rotated = true;
         }
     }
 
+    if moved_or_rotated {
+        // these build up over many frames so they have to be kept rigid here too
+        movement_control.main_transform = movement_control.main_transform.normalised();
+        movement_control.xy_rotation = movement_control.xy_rotation.normalized();
+    }
+
     if moved_or_rotated || movement_control.is_changed() {
-        *out_transform = movement_control
-            .main_transform
-            .then(movement_control.xy_rotation.into());
+        let main_transform = movement_control.main_transform;
+        *out_transform = main_transform.with_rotor(
+            main_transform
+                .rotor_part()
+                .then(movement_control.xy_rotation),
+        );
     }
 }